@@ -0,0 +1,255 @@
+//! Micro-benchmarks for key creation, lookup and manipulation
+//!
+//! Run with `cargo bench`. Each case is timed with `std::time::Instant`, so no
+//! benchmark harness crate is needed: the iteration count doubles until a run
+//! takes at least 100ms, and the mean time per iteration is printed. Without
+//! `--bench` (for example under `cargo test --benches`) every case runs once
+//! as a smoke test.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use domain_key::{CharTable, DefaultDomain, Key, KeyDomain, KeyMap, PathDomain};
+
+/// Times benchmark cases, or runs each once outside `cargo bench`
+struct Runner {
+    full: bool,
+}
+
+impl Runner {
+    fn from_args() -> Self {
+        Self {
+            full: std::env::args().any(|arg| arg == "--bench"),
+        }
+    }
+
+    /// Prints a section header so related cases are grouped in the output
+    fn group(&self, title: &str) {
+        if self.full {
+            println!("\n{title}");
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn run<R>(&self, name: &str, mut f: impl FnMut() -> R) {
+        if !self.full {
+            black_box(f());
+            return;
+        }
+
+        let mut iterations = 1u64;
+        loop {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(f());
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= Duration::from_millis(100) {
+                let per_iter = elapsed.as_nanos() as f64 / iterations as f64;
+                println!("  {name:<44} {per_iter:>10.1} ns/iter");
+                return;
+            }
+            iterations *= 2;
+        }
+    }
+}
+
+// ============================================================================
+// CHARACTER TABLES
+// ============================================================================
+
+const TABLE: CharTable = CharTable::ascii_alphanumeric()
+    .with('_')
+    .with('-')
+    .with('.');
+
+fn branch_allowed(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+fn char_tables(runner: &Runner) {
+    runner.group("character tables");
+
+    let input = "tenant_42.users-profile.settings_v2";
+    runner.run("CharTable::contains over 35 chars", || {
+        black_box(input).chars().all(|c| TABLE.contains(c))
+    });
+    runner.run("branching check over 35 chars", || {
+        black_box(input).chars().all(branch_allowed)
+    });
+    runner.run("Key::<DefaultDomain>::new", || {
+        Key::<DefaultDomain>::new(black_box(input))
+    });
+    runner.run("Key::<PathDomain>::new", || {
+        Key::<PathDomain>::new(black_box("tenant/users/profile/settings"))
+    });
+}
+
+// ============================================================================
+// JOINING KEYS
+// ============================================================================
+
+fn joining(runner: &Runner) {
+    runner.group("joining five keys");
+
+    let keys: Vec<Key<DefaultDomain>> = ["tenant_42", "users", "profile", "settings", "v2"]
+        .iter()
+        .map(|part| Key::new(part).unwrap())
+        .collect();
+    let refs: Vec<&Key<DefaultDomain>> = keys.iter().collect();
+
+    runner.run("Key::concat_keys", || {
+        Key::concat_keys(black_box(&refs), '.')
+    });
+    runner.run("as_str + Key::from_parts", || {
+        let parts: Vec<&str> = black_box(&refs).iter().map(|key| key.as_str()).collect();
+        Key::<DefaultDomain>::from_parts(&parts, ".")
+    });
+}
+
+// ============================================================================
+// ASCII-ONLY DOMAINS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct AsciiDomain;
+
+impl KeyDomain for AsciiDomain {
+    const DOMAIN_NAME: &'static str = "ascii";
+    const ASCII_ONLY: bool = true;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct CharWiseDomain;
+
+impl KeyDomain for CharWiseDomain {
+    const DOMAIN_NAME: &'static str = "char_wise";
+}
+
+fn ascii_only(runner: &Runner) {
+    runner.group("validating a 32-char ASCII key");
+
+    let input = "tenant_42.users-profile.settings";
+    runner.run("ASCII_ONLY = true (byte scan)", || {
+        Key::<AsciiDomain>::new(black_box(input))
+    });
+    runner.run("ASCII_ONLY = false (char scan)", || {
+        Key::<CharWiseDomain>::new(black_box(input))
+    });
+}
+
+// ============================================================================
+// PART ACCESS
+// ============================================================================
+
+fn part_access(runner: &Runner) {
+    runner.group("reading every part of an 8-part path");
+
+    let input = "tenant/users/profile/settings/2024/q3/reports/summary";
+    let cached = Key::<PathDomain>::new(input).unwrap();
+    let uncached = Key::<DefaultDomain>::new(input.replace('/', ".")).unwrap();
+
+    runner.run("PathDomain nth_part (cached positions)", || {
+        let key = black_box(&cached);
+        (0..key.part_count('/'))
+            .filter_map(|n| key.nth_part('/', n))
+            .count()
+    });
+    runner.run("DefaultDomain nth_part (split().nth)", || {
+        let key = black_box(&uncached);
+        (0..key.part_count('.'))
+            .filter_map(|n| key.nth_part('.', n))
+            .count()
+    });
+}
+
+// ============================================================================
+// ASCII INPUT
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct UnicodeDomain;
+
+impl KeyDomain for UnicodeDomain {
+    const DOMAIN_NAME: &'static str = "unicode";
+
+    fn allowed_characters(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+}
+
+fn ascii_input(runner: &Runner) {
+    runner.group("creating a 16-char key in a Unicode domain");
+
+    runner.run("ASCII input (byte scan)", || {
+        Key::<UnicodeDomain>::new(black_box("tenant_42_users1"))
+    });
+    runner.run("one non-ASCII char (char scan)", || {
+        Key::<UnicodeDomain>::new(black_box("tenant_42_us\u{e9}rs1"))
+    });
+}
+
+// ============================================================================
+// CLONING
+// ============================================================================
+
+fn cloning(runner: &Runner) {
+    // Compare the two storage modes by running once plain and once with
+    // `cargo bench --features shared`
+    let mode = if cfg!(feature = "shared") {
+        "Arc<str>"
+    } else {
+        "SmartString"
+    };
+    runner.group(&format!("cloning a 64-char key ({mode} storage)"));
+
+    let key = Key::<DefaultDomain>::new("a".repeat(64)).unwrap();
+    runner.run("Key::clone", || black_box(&key).clone());
+}
+
+// ============================================================================
+// MAP LOOKUPS
+// ============================================================================
+
+fn map_lookups(runner: &Runner) {
+    runner.group("looking up 1000 keys");
+
+    let keys: Vec<Key<DefaultDomain>> = (0..1000)
+        .map(|i| Key::new(format!("tenant_{i}.users")).unwrap())
+        .collect();
+    let key_map: KeyMap<DefaultDomain, usize> = keys
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, key)| (key, i))
+        .collect();
+    let std_map: HashMap<Key<DefaultDomain>, usize> = keys
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, key)| (key, i))
+        .collect();
+
+    runner.run("KeyMap (BuildKeyHasher)", || {
+        keys.iter()
+            .filter_map(|key| black_box(&key_map).get(key))
+            .sum::<usize>()
+    });
+    runner.run("HashMap (RandomState)", || {
+        keys.iter()
+            .filter_map(|key| black_box(&std_map).get(key))
+            .sum::<usize>()
+    });
+}
+
+fn main() {
+    let runner = Runner::from_args();
+    char_tables(&runner);
+    joining(&runner);
+    ascii_only(&runner);
+    part_access(&runner);
+    ascii_input(&runner);
+    cloning(&runner);
+    map_lookups(&runner);
+}
@@ -28,7 +28,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Key properties
     println!("\nKey properties:");
     println!("  User 1 length: {}", user1.len());
-    println!("  User 1 hash: 0x{:x}", user1.hash());
+    println!("  User 1 hash: 0x{}", user1.hash_hex());
 
     // String operations
     println!("\nString operations:");
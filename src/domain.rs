@@ -9,11 +9,16 @@ use core::hash::Hash;
 
 #[cfg(not(feature = "std"))]
 use alloc::borrow::Cow;
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::borrow::Cow;
 
 use crate::error::KeyParseError;
-use crate::key::DEFAULT_MAX_KEY_LENGTH;
+use crate::key::{Key, DEFAULT_MAX_KEY_LENGTH};
+use crate::utils::char_validation::CharTable;
 
 // ============================================================================
 // KEY DOMAIN TRAIT
@@ -138,6 +143,28 @@ pub trait KeyDomain:
     /// comparisons are optimized.
     const CASE_INSENSITIVE: bool = true;
 
+    /// Whether keys in this domain must consist of ASCII characters only
+    ///
+    /// When `true`, character validation scans the key byte by byte and
+    /// never decodes UTF-8, which is noticeably faster for short keys.
+    /// Any non-ASCII character is rejected as an invalid character.
+    const ASCII_ONLY: bool = false;
+
+    /// Whether keys in this domain are redacted when displayed
+    ///
+    /// When `true`, both `Display` and `Debug` for `Key<T>` print the redacted
+    /// form from `Key::redacted` instead of the key content, and `Key::reveal`
+    /// must be used to get at the value. Use for domains whose keys embed user
+    /// identifiers or other values that must not reach logs.
+    const REDACT_IN_DISPLAY: bool = false;
+
+    /// Version of this domain's validation and normalization rules
+    ///
+    /// Bump this whenever a rule change could make previously stored keys
+    /// non-canonical. Stored keys can record the version they were created
+    /// under so migrations can detect and re-normalize stale keys.
+    const SCHEMA_VERSION: u32 = 1;
+
     /// Domain-specific validation rules
     ///
     /// This method is called after common validation passes.
@@ -169,6 +196,46 @@ pub trait KeyDomain:
         Ok(()) // Default: no domain-specific validation
     }
 
+    /// Hash-aware validation rules
+    ///
+    /// This method is called after the key's hash has been computed, allowing
+    /// domains to reject keys based on their hash (for example, a known-bad
+    /// hash set or a hash-prefix scheme) without hashing the key a second time.
+    ///
+    /// # Performance Considerations
+    ///
+    /// This method runs on every key creation, so it must be cheap. Prefer
+    /// constant-time checks such as bit masks or small sorted lookups.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The normalized key string
+    /// * `hash` - The hash that will be cached in the key
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the key must be rejected based on its hash.
+    /// Use `KeyParseError::domain_error` for consistent error formatting.
+    fn post_validate(key: &str, hash: u64) -> Result<(), KeyParseError> {
+        let _ = (key, hash);
+        Ok(()) // Default: no hash-based validation
+    }
+
+    /// Observer called after a key has been created successfully
+    ///
+    /// Runs once per validated key, after all validation hooks have passed.
+    /// Use it to count creations or log for debugging. The default is empty
+    /// and inlined, so domains that don't override it pay nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The normalized key string
+    #[inline(always)]
+    #[allow(clippy::inline_always)]
+    fn on_create(key: &str) {
+        let _ = key;
+    }
+
     /// Check which characters are allowed for this domain
     ///
     /// Override this method to define domain-specific character restrictions.
@@ -216,6 +283,59 @@ pub trait KeyDomain:
         key // Default: no additional normalization
     }
 
+    /// Normalize and validate a key in a single pass
+    ///
+    /// Key creation calls this after common normalization (trimming,
+    /// lowercasing). The default runs [`KeyDomain::normalize_domain`] followed
+    /// by [`KeyDomain::validate_domain_rules`]. Domains whose rules are really
+    /// "normalize, then the result must have this shape" can override this
+    /// hook instead of both, so the key is scanned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The key string after common normalization
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the key doesn't meet domain-specific
+    /// validation requirements
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain, KeyParseError};
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TicketDomain;
+    /// impl KeyDomain for TicketDomain {
+    ///     const DOMAIN_NAME: &'static str = "ticket";
+    ///
+    ///     // Accept `123` or `t-123`, store `t-123`
+    ///     fn canonicalize(input: Cow<'_, str>) -> Result<Cow<'_, str>, KeyParseError> {
+    ///         let digits = input.strip_prefix("t-").unwrap_or(&input);
+    ///         if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+    ///             return Err(KeyParseError::domain_error("ticket", "expected a ticket number"));
+    ///         }
+    ///         if input.starts_with("t-") {
+    ///             Ok(input)
+    ///         } else {
+    ///             Ok(Cow::Owned(format!("t-{digits}")))
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Key::<TicketDomain>::new("42")?.as_str(), "t-42");
+    /// assert_eq!(Key::<TicketDomain>::new("T-42")?.as_str(), "t-42");
+    /// assert!(Key::<TicketDomain>::new("t-x").is_err());
+    /// # Ok::<(), KeyParseError>(())
+    /// ```
+    fn canonicalize(input: Cow<'_, str>) -> Result<Cow<'_, str>, KeyParseError> {
+        let normalized = Self::normalize_domain(input);
+        Self::validate_domain_rules(&normalized)?;
+        Ok(normalized)
+    }
+
     /// Check if a key has a reserved prefix for this domain
     ///
     /// Override this method to define domain-specific reserved prefixes.
@@ -278,6 +398,20 @@ pub trait KeyDomain:
         &[] // Default: no examples
     }
 
+    /// Get the designated default key for this domain, if any
+    ///
+    /// Empty keys are always invalid, so keys have no universal default.
+    /// Domains that want a sentinel or placeholder value can name one here;
+    /// it is validated like any other input by [`Key::domain_default`].
+    ///
+    /// # Returns
+    ///
+    /// The default key string, or `None` if the domain has no default
+    #[must_use]
+    fn default_key() -> Option<&'static str> {
+        None // Default: no default key
+    }
+
     /// Get the default separator character for this domain
     ///
     /// This is used when composing keys from multiple parts.
@@ -294,7 +428,8 @@ pub trait KeyDomain:
     /// Check if the key contains only ASCII characters
     ///
     /// Some domains might require ASCII-only keys for compatibility reasons.
-    /// Override this method if your domain has specific ASCII requirements.
+    /// This now delegates to [`KeyDomain::ASCII_ONLY`], which is what key
+    /// validation actually consults.
     ///
     /// # Arguments
     ///
@@ -303,10 +438,14 @@ pub trait KeyDomain:
     /// # Returns
     ///
     /// `true` if ASCII-only is required, `false` otherwise
+    #[deprecated(
+        since = "0.1.2",
+        note = "set `KeyDomain::ASCII_ONLY` instead; the requirement does not depend on the key"
+    )]
     #[must_use]
     fn requires_ascii_only(key: &str) -> bool {
         let _ = key;
-        false // Default: allow Unicode
+        Self::ASCII_ONLY
     }
 
     /// Get the minimum allowed length for keys in this domain
@@ -386,6 +525,7 @@ pub trait KeyDomain:
 /// and optimization hints, useful for debugging and introspection.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct DomainInfo {
     /// Domain name
     pub name: &'static str,
@@ -403,6 +543,8 @@ pub struct DomainInfo {
     pub frequently_split: bool,
     /// Whether case insensitive
     pub case_insensitive: bool,
+    /// Version of the domain's rules
+    pub schema_version: u32,
     /// Whether has custom validation
     pub has_custom_validation: bool,
     /// Whether has custom normalization
@@ -418,6 +560,7 @@ pub struct DomainInfo {
 impl fmt::Display for DomainInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Domain: {}", self.name)?;
+        writeln!(f, "Schema version: {}", self.schema_version)?;
         writeln!(
             f,
             "Length: {}-{} (expected: {})",
@@ -480,6 +623,7 @@ pub fn domain_info<T: KeyDomain>() -> DomainInfo {
         frequently_compared: T::FREQUENTLY_COMPARED,
         frequently_split: T::FREQUENTLY_SPLIT,
         case_insensitive: T::CASE_INSENSITIVE,
+        schema_version: T::SCHEMA_VERSION,
         has_custom_validation: T::HAS_CUSTOM_VALIDATION,
         has_custom_normalization: T::HAS_CUSTOM_NORMALIZATION,
         default_separator: T::default_separator(),
@@ -500,6 +644,40 @@ pub fn domains_compatible<T1: KeyDomain, T2: KeyDomain>() -> bool {
         && T1::default_separator() == T2::default_separator()
 }
 
+/// Marker trait declaring that every key of this domain is valid in `Parent`
+///
+/// Implementing `SubdomainOf<Parent>` is a promise that any string accepted
+/// and normalized by this domain is also accepted by `Parent` and already in
+/// `Parent`'s normalized form. [`Key::widen`] relies on that promise to move
+/// keys into the looser domain without revalidation. The narrowing direction
+/// stays fallible via [`Key::try_into_domain`].
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{DefaultDomain, Key, KeyDomain, SubdomainOf};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct SlugDomain;
+/// impl KeyDomain for SlugDomain {
+///     const DOMAIN_NAME: &'static str = "slug";
+///     const MAX_LENGTH: usize = 32;
+///     fn allowed_characters(c: char) -> bool {
+///         c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'
+///     }
+/// }
+///
+/// // Lowercase alphanumerics and `_` within 32 bytes are always valid
+/// // `DefaultDomain` keys
+/// impl SubdomainOf<DefaultDomain> for SlugDomain {}
+///
+/// let slug = Key::<SlugDomain>::new("release_notes")?;
+/// let general: Key<DefaultDomain> = slug.widen();
+/// assert_eq!(general.as_str(), "release_notes");
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+pub trait SubdomainOf<Parent: KeyDomain>: KeyDomain {}
+
 // ============================================================================
 // BUILT-IN DOMAIN IMPLEMENTATIONS
 // ============================================================================
@@ -526,6 +704,12 @@ pub fn domains_compatible<T1: KeyDomain, T2: KeyDomain>() -> bool {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct DefaultDomain;
 
+/// Characters allowed in [`DefaultDomain`] keys
+const DEFAULT_CHARS: CharTable = CharTable::ascii_alphanumeric()
+    .with('_')
+    .with('-')
+    .with('.');
+
 impl KeyDomain for DefaultDomain {
     const DOMAIN_NAME: &'static str = "default";
     const MAX_LENGTH: usize = 64;
@@ -533,6 +717,10 @@ impl KeyDomain for DefaultDomain {
     const TYPICALLY_SHORT: bool = true;
     const CASE_INSENSITIVE: bool = true;
 
+    fn allowed_characters(c: char) -> bool {
+        DEFAULT_CHARS.contains(c)
+    }
+
     fn validation_help() -> Option<&'static str> {
         Some("Use alphanumeric characters, underscores, hyphens, and dots. Case insensitive.")
     }
@@ -625,6 +813,13 @@ impl KeyDomain for IdentifierDomain {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PathDomain;
 
+/// Characters allowed in [`PathDomain`] keys
+const PATH_CHARS: CharTable = CharTable::ascii_alphanumeric()
+    .with('_')
+    .with('-')
+    .with('.')
+    .with('/');
+
 impl KeyDomain for PathDomain {
     const DOMAIN_NAME: &'static str = "path";
     const MAX_LENGTH: usize = 256;
@@ -635,7 +830,7 @@ impl KeyDomain for PathDomain {
     const HAS_CUSTOM_VALIDATION: bool = true;
 
     fn allowed_characters(c: char) -> bool {
-        c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.' || c == '/'
+        PATH_CHARS.contains(c)
     }
 
     fn allowed_start_character(c: char) -> bool {
@@ -682,6 +877,148 @@ impl KeyDomain for PathDomain {
     }
 }
 
+// ============================================================================
+// RUNTIME DOMAINS
+// ============================================================================
+
+/// Domain for keys whose rules are only known at runtime
+///
+/// `DynamicDomain` itself accepts any non-whitespace, non-control characters
+/// up to 1024 bytes. The actual rules come from a [`RuntimeDomain`], and keys
+/// should be created through [`RuntimeDomain::new_key`] so those rules apply.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DynamicDomain;
+
+impl KeyDomain for DynamicDomain {
+    const DOMAIN_NAME: &'static str = "dynamic";
+    const MAX_LENGTH: usize = 1024;
+    const CASE_INSENSITIVE: bool = false;
+
+    fn allowed_characters(c: char) -> bool {
+        !c.is_whitespace() && !c.is_control()
+    }
+
+    fn allowed_start_character(c: char) -> bool {
+        Self::allowed_characters(c)
+    }
+
+    fn allowed_end_character(c: char) -> bool {
+        Self::allowed_characters(c)
+    }
+
+    fn allowed_consecutive_characters(_prev: char, _curr: char) -> bool {
+        true
+    }
+}
+
+/// Key constraints loaded from configuration
+///
+/// Describes a domain as data instead of a Rust type, so validation rules
+/// can be read from JSON or TOML at startup. Keys are always allowed to
+/// contain ASCII letters and digits; `allowed_characters` lists the extra
+/// characters on top of those. `pattern` is an optional glob where `*`
+/// matches any run of characters and `?` matches exactly one.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::RuntimeDomain;
+///
+/// let domain: RuntimeDomain = serde_json::from_str(
+///     r#"{ "name": "sku", "max_length": 16, "allowed_characters": "-", "pattern": "sku-*" }"#,
+/// ).unwrap();
+///
+/// let key = domain.new_key("sku-1234")?;
+/// assert_eq!(key.as_str(), "sku-1234");
+/// assert!(domain.new_key("item-1234").is_err());
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RuntimeDomain {
+    /// Domain name used in error messages
+    pub name: String,
+    /// Minimum allowed length
+    pub min_length: usize,
+    /// Maximum allowed length, capped at `DynamicDomain::MAX_LENGTH`
+    pub max_length: usize,
+    /// Characters allowed in addition to ASCII letters and digits
+    pub allowed_characters: String,
+    /// Whether keys are lowercased before validation
+    pub case_insensitive: bool,
+    /// Optional glob pattern the whole key must match
+    pub pattern: Option<String>,
+}
+
+impl Default for RuntimeDomain {
+    fn default() -> Self {
+        Self {
+            name: DynamicDomain::DOMAIN_NAME.to_string(),
+            min_length: 1,
+            max_length: DEFAULT_MAX_KEY_LENGTH,
+            allowed_characters: "_-.".to_string(),
+            case_insensitive: false,
+            pattern: None,
+        }
+    }
+}
+
+impl RuntimeDomain {
+    /// Check whether a character is allowed by this domain
+    #[must_use]
+    pub fn allows(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || self.allowed_characters.contains(c)
+    }
+
+    /// Validate a key against this domain and create it
+    ///
+    /// The input is trimmed and, for case-insensitive domains, lowercased
+    /// before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the key violates any of the domain's rules
+    pub fn new_key(&self, key: &str) -> Result<Key<DynamicDomain>, KeyParseError> {
+        let trimmed = key.trim();
+        if self.case_insensitive && trimmed.chars().any(|c| c.is_ascii_uppercase()) {
+            let lowered = trimmed.to_ascii_lowercase();
+            crate::validation::validate_runtime(self, &lowered)?;
+            Key::from_string(lowered)
+        } else {
+            crate::validation::validate_runtime(self, trimmed)?;
+            Key::new(trimmed)
+        }
+    }
+}
+
+/// Match `text` against a glob where `*` matches any run and `?` one character
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -706,6 +1043,8 @@ mod tests {
         assert_eq!(info.max_length, 64);
         assert!(info.case_insensitive);
         assert!(!info.has_custom_validation);
+        assert_eq!(info.schema_version, 1);
+        assert!(info.to_string().contains("Schema version: 1"));
     }
 
     #[test]
@@ -763,7 +1102,9 @@ mod tests {
         assert!(DefaultDomain::allowed_characters('a'));
         assert!(!DefaultDomain::is_reserved_prefix("test"));
         assert!(!DefaultDomain::is_reserved_suffix("test"));
-        assert!(!DefaultDomain::requires_ascii_only("test"));
+        #[allow(deprecated)]
+        let ascii_only = DefaultDomain::requires_ascii_only("test");
+        assert!(!ascii_only);
         assert_eq!(DefaultDomain::min_length(), 1);
 
         // Test validation help
@@ -771,6 +1112,18 @@ mod tests {
         assert!(!DefaultDomain::examples().is_empty());
     }
 
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("user_?", "user_1"));
+        assert!(!glob_match("user_?", "user_12"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxbyy"));
+        assert!(glob_match("exact", "exact"));
+        assert!(!glob_match("exact", "exacts"));
+    }
+
     #[test]
     fn test_normalization() {
         // Test default normalization (no change)
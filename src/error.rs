@@ -84,6 +84,29 @@ pub enum KeyParseError {
         actual_length: usize,
     },
 
+    /// Key is shorter than the minimum length required by the domain
+    ///
+    /// Domains can require more than one character via `min_length()`. This
+    /// error provides both the minimum and the actual length.
+    #[error("Key is too short (min {min_length} characters, got {actual_length})")]
+    TooShort {
+        /// The minimum required length for this domain
+        min_length: usize,
+        /// The actual length of the key that was attempted
+        actual_length: usize,
+    },
+
+    /// Key bytes are not valid UTF-8
+    ///
+    /// Returned when a key is created from raw bytes, for example via
+    /// `TryFrom<&[u8]>`. The position is the byte offset where the invalid
+    /// sequence starts.
+    #[error("Key is not valid UTF-8 (invalid byte sequence at position {position})")]
+    InvalidUtf8 {
+        /// Byte offset of the first invalid sequence (0-based)
+        position: usize,
+    },
+
     /// Key has invalid structure (consecutive special chars, invalid start/end)
     ///
     /// This covers structural issues like:
@@ -215,7 +238,11 @@ impl KeyParseError {
     /// - `1002`: Invalid character
     /// - `1003`: Key too long
     /// - `1004`: Invalid structure
+    /// - `1005`: Key too short
+    /// - `1006`: Invalid UTF-8
     /// - `2000`: Domain validation (base code)
+    /// - `3001`: Validation budget exceeded (a `Custom` error, see `ValidationBudget`)
+    /// - `3002`: Reading keys failed (a `Custom` error, see `validation::validate_reader`)
     /// - Custom codes: As specified in `Custom` errors
     ///
     /// # Examples
@@ -233,6 +260,8 @@ impl KeyParseError {
             Self::InvalidCharacter { .. } => 1002,
             Self::TooLong { .. } => 1003,
             Self::InvalidStructure { .. } => 1004,
+            Self::TooShort { .. } => 1005,
+            Self::InvalidUtf8 { .. } => 1006,
             Self::DomainValidation { .. } => 2000,
             Self::Custom { code, .. } => *code,
         }
@@ -257,14 +286,50 @@ impl KeyParseError {
     #[must_use]
     pub const fn category(&self) -> ErrorCategory {
         match self {
-            Self::Empty | Self::TooLong { .. } => ErrorCategory::Length,
-            Self::InvalidCharacter { .. } => ErrorCategory::Character,
+            Self::Empty | Self::TooLong { .. } | Self::TooShort { .. } => ErrorCategory::Length,
+            Self::InvalidCharacter { .. } | Self::InvalidUtf8 { .. } => ErrorCategory::Character,
             Self::InvalidStructure { .. } => ErrorCategory::Structure,
             Self::DomainValidation { .. } => ErrorCategory::Domain,
             Self::Custom { .. } => ErrorCategory::Custom,
         }
     }
 
+    /// Get the position in the key where the error was detected
+    ///
+    /// Returns the 0-based character position for errors that carry one,
+    /// such as `InvalidCharacter` (or the byte offset for `InvalidUtf8`),
+    /// and `None` for all other errors. This lets
+    /// error-rendering code point at the offending character without matching
+    /// on individual variants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::KeyParseError;
+    ///
+    /// let error = KeyParseError::InvalidCharacter {
+    ///     character: '!',
+    ///     position: 4,
+    ///     expected: None,
+    /// };
+    /// assert_eq!(error.position(), Some(4));
+    /// assert_eq!(KeyParseError::Empty.position(), None);
+    /// ```
+    #[must_use]
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            Self::InvalidCharacter { position, .. } | Self::InvalidUtf8 { position } => {
+                Some(*position)
+            }
+            Self::Empty
+            | Self::TooLong { .. }
+            | Self::TooShort { .. }
+            | Self::InvalidStructure { .. }
+            | Self::DomainValidation { .. }
+            | Self::Custom { .. } => None,
+        }
+    }
+
     /// Get a human-readable description of what went wrong
     ///
     /// This provides additional context beyond the basic error message,
@@ -277,6 +342,10 @@ impl KeyParseError {
                 "Key contains characters that are not allowed by the domain"
             }
             Self::TooLong { .. } => "Key exceeds the maximum length allowed by the domain",
+            Self::TooShort { .. } => {
+                "Key is shorter than the minimum length required by the domain"
+            }
+            Self::InvalidUtf8 { .. } => "Key bytes are not valid UTF-8",
             Self::InvalidStructure { .. } => "Key has invalid structure or formatting",
             Self::DomainValidation { .. } => "Key fails domain-specific validation rules",
             Self::Custom { .. } => "Key fails custom validation rules",
@@ -301,6 +370,14 @@ impl KeyParseError {
                 "Shorten the key to fit within length limits",
                 "Consider using abbreviated forms",
             ],
+            Self::TooShort { .. } => vec![
+                "Lengthen the key to meet the minimum length",
+                "Check the domain's minimum length requirement",
+            ],
+            Self::InvalidUtf8 { .. } => vec![
+                "Ensure the key is UTF-8 encoded",
+                "Check the source of the bytes for corruption",
+            ],
             Self::InvalidStructure { .. } => vec![
                 "Avoid consecutive special characters",
                 "Don't start or end with special characters",
@@ -327,6 +404,8 @@ impl KeyParseError {
             Self::Empty
             | Self::InvalidCharacter { .. }
             | Self::TooLong { .. }
+            | Self::TooShort { .. }
+            | Self::InvalidUtf8 { .. }
             | Self::InvalidStructure { .. }
             | Self::DomainValidation { .. } => true,
             Self::Custom { .. } => false, // Depends on the specific custom error
@@ -344,7 +423,7 @@ impl KeyParseError {
 /// errors uniformly, regardless of the specific error details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorCategory {
-    /// Length-related errors (empty, too long)
+    /// Length-related errors (empty, too long, too short)
     Length,
     /// Character-related errors (invalid characters)
     Character,
@@ -498,6 +577,15 @@ pub fn too_long(max_length: usize, actual_length: usize) -> KeyParseError {
     }
 }
 
+/// Create a "too short" error
+#[must_use]
+pub fn too_short(min_length: usize, actual_length: usize) -> KeyParseError {
+    KeyParseError::TooShort {
+        min_length,
+        actual_length,
+    }
+}
+
 /// Create an invalid structure error
 #[must_use]
 pub fn invalid_structure(reason: &'static str) -> KeyParseError {
@@ -582,6 +670,15 @@ mod tests {
             KeyParseError::InvalidStructure { reason: "test" }.code(),
             1004
         );
+        assert_eq!(
+            KeyParseError::TooShort {
+                min_length: 4,
+                actual_length: 2
+            }
+            .code(),
+            1005
+        );
+        assert_eq!(KeyParseError::InvalidUtf8 { position: 3 }.code(), 1006);
         assert_eq!(
             KeyParseError::DomainValidation {
                 domain: "test",
@@ -620,6 +717,10 @@ mod tests {
             .category(),
             ErrorCategory::Length
         );
+        assert_eq!(
+            KeyParseError::InvalidUtf8 { position: 0 }.category(),
+            ErrorCategory::Character
+        );
         assert_eq!(
             KeyParseError::InvalidStructure { reason: "test" }.category(),
             ErrorCategory::Structure
@@ -642,6 +743,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_position() {
+        let error = invalid_character('$', 7, None);
+        assert_eq!(error.position(), Some(7));
+
+        assert_eq!(KeyParseError::Empty.position(), None);
+        assert_eq!(too_long(8, 12).position(), None);
+        assert_eq!(invalid_structure("bad").position(), None);
+        assert_eq!(KeyParseError::custom(1, "custom").position(), None);
+    }
+
     #[test]
     fn test_error_suggestions() {
         let error = KeyParseError::Empty;
@@ -720,6 +832,11 @@ mod tests {
             expected: None
         }
         .is_recoverable());
+        assert!(KeyParseError::TooShort {
+            min_length: 4,
+            actual_length: 2
+        }
+        .is_recoverable());
         assert!(!KeyParseError::Custom {
             code: 42,
             message: "msg".to_string()
@@ -6,6 +6,14 @@
 
 use core::fmt;
 
+#[cfg(not(feature = "std"))]
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+use crate::domain::KeyDomain;
+use crate::key::Key;
+
 #[cfg(not(feature = "std"))]
 use alloc::format;
 #[cfg(not(feature = "std"))]
@@ -228,6 +236,59 @@ pub const fn hash_algorithm() -> &'static str {
     }
 }
 
+/// Returns the hash algorithm actually engaged on the running CPU
+///
+/// [`hash_algorithm`] reports the compile-time selection. This function
+/// additionally checks the CPU the binary is running on, using the same
+/// AES capability the `GxHash` path relies on, so a build that selected
+/// `GxHash` at compile time but runs on a CPU without AES support is reported
+/// as such. Without `std` (no runtime detection) or without the `fast`
+/// feature, this returns the same value as [`hash_algorithm`].
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::features::{active_hash_runtime, hash_algorithm};
+///
+/// let runtime = active_hash_runtime();
+/// assert!(runtime.starts_with(hash_algorithm().split(' ').next().unwrap()));
+/// ```
+#[must_use]
+pub fn active_hash_runtime() -> &'static str {
+    #[cfg(all(
+        feature = "fast",
+        feature = "std",
+        any(
+            all(target_arch = "x86_64", target_feature = "aes"),
+            all(target_arch = "aarch64", target_feature = "aes")
+        )
+    ))]
+    {
+        #[cfg(target_arch = "x86_64")]
+        let detected = std::arch::is_x86_feature_detected!("aes");
+        #[cfg(target_arch = "aarch64")]
+        let detected = std::arch::is_aarch64_feature_detected!("aes");
+
+        if detected {
+            "GxHash"
+        } else {
+            "GxHash (AES not detected at runtime)"
+        }
+    }
+
+    #[cfg(not(all(
+        feature = "fast",
+        feature = "std",
+        any(
+            all(target_arch = "x86_64", target_feature = "aes"),
+            all(target_arch = "aarch64", target_feature = "aes")
+        )
+    )))]
+    {
+        hash_algorithm()
+    }
+}
+
 /// Returns the hash algorithm category
 #[must_use]
 pub const fn hash_category() -> HashCategory {
@@ -371,6 +432,106 @@ pub fn performance_info() -> PerformanceInfo {
     }
 }
 
+// ============================================================================
+// HASHING
+// ============================================================================
+
+/// Hashes a string exactly as a key of domain `T` would be hashed
+///
+/// The input is trimmed, lowercased for case-insensitive domains and passed
+/// through `KeyDomain::canonicalize` before hashing with the
+/// feature-selected algorithm, so for valid input the result equals
+/// `Key::<T>::new(s)?.hash()`. No validation beyond `canonicalize` is
+/// performed. If `canonicalize` rejects the input, the trimmed and
+/// lowercased form is hashed instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{hash_str, Key, KeyDomain};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct UserDomain;
+/// impl KeyDomain for UserDomain {
+///     const DOMAIN_NAME: &'static str = "user";
+/// }
+///
+/// let key = Key::<UserDomain>::new("Alice")?;
+/// assert_eq!(hash_str::<UserDomain>(" Alice "), key.hash());
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+#[must_use]
+pub fn hash_str<T: KeyDomain>(s: &str) -> u64 {
+    let common = Key::<T>::normalize_common::<T>(s);
+    match T::canonicalize(Cow::Borrowed(&common)) {
+        Ok(canonical) => Key::<T>::compute_hash(&canonical),
+        // No key exists for rejected input, so any stable hash will do
+        Err(_) => Key::<T>::compute_hash(&common),
+    }
+}
+
+/// Process-wide seed for the `secure` hash path
+#[cfg(all(feature = "secure", feature = "std", not(feature = "fast")))]
+static HASH_SEED: std::sync::OnceLock<[u64; 4]> = std::sync::OnceLock::new();
+
+/// Sets the seed used by the `secure` hash path for this process
+///
+/// Seeding applies only when `secure` and `std` are enabled and `fast` is
+/// not: with `fast` the `GxHash`/`AHash` path is selected instead, and without
+/// `std` there is no process-wide storage for the seed. In those builds this
+/// function and [`hash_seed`] do not exist.
+///
+/// By default the seed is drawn from the standard library's random source
+/// the first time a key is hashed. Call this before creating any keys to
+/// use a seed of your own, for example to share hashes between processes
+/// that trust each other.
+///
+/// Seeding makes the cached hash differ between runs, so `Key::hash()`
+/// must not be persisted or compared across processes that do not share
+/// the seed. `CompactKey` recomputes the hash on load for this reason.
+///
+/// # Errors
+///
+/// Returns the rejected seed if the seed was already set or drawn
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::features::{hash_seed, set_hash_seed};
+///
+/// let _ = set_hash_seed([1, 2, 3, 4]);
+/// assert_eq!(set_hash_seed([5, 6, 7, 8]), Err([5, 6, 7, 8]));
+/// assert_ne!(hash_seed(), [5, 6, 7, 8]);
+/// ```
+#[cfg(all(feature = "secure", feature = "std", not(feature = "fast")))]
+pub fn set_hash_seed(seed: [u64; 4]) -> Result<(), [u64; 4]> {
+    HASH_SEED.set(seed)
+}
+
+/// Returns the seed used by the `secure` hash path, drawing it if unset
+///
+/// See [`set_hash_seed`] for the consequences of seeding.
+#[cfg(all(feature = "secure", feature = "std", not(feature = "fast")))]
+pub fn hash_seed() -> [u64; 4] {
+    *HASH_SEED.get_or_init(|| {
+        use std::hash::BuildHasher;
+
+        let state = std::collections::hash_map::RandomState::new();
+        [0u64, 1, 2, 3].map(|i| state.hash_one(i))
+    })
+}
+
+/// Hashes `bytes` with `AHash` keyed by `seed`
+#[cfg(all(feature = "secure", feature = "std", not(feature = "fast")))]
+pub(crate) fn seeded_hash(seed: [u64; 4], bytes: &[u8]) -> u64 {
+    use core::hash::{BuildHasher, Hasher};
+
+    let mut hasher =
+        ahash::RandomState::with_seeds(seed[0], seed[1], seed[2], seed[3]).build_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
 // ============================================================================
 // OPTIMIZATION DETECTION
 // ============================================================================
@@ -536,6 +697,157 @@ pub fn feature_recommendations() -> FeatureRecommendations {
     }
 }
 
+/// Typical deployment scenarios used for concrete feature guidance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UseCase {
+    /// High-throughput web services on modern server CPUs
+    HighThroughputWeb,
+    /// Services exposed to untrusted input that need `DoS` resistance
+    SecuritySensitive,
+    /// Applications requiring cryptographically secure key hashing
+    Cryptographic,
+    /// Embedded or `no_std` targets with minimal dependencies
+    Embedded,
+    /// General-purpose applications with no special requirements
+    General,
+}
+
+impl UseCase {
+    /// Returns the recommended feature set for this use case
+    #[must_use]
+    pub const fn features(self) -> &'static [&'static str] {
+        match self {
+            Self::HighThroughputWeb => &["fast", "std", "serde"],
+            Self::SecuritySensitive => &["secure", "std", "serde"],
+            Self::Cryptographic => &["crypto", "std", "serde"],
+            Self::Embedded => &["no_std"],
+            Self::General => &["std", "serde"],
+        }
+    }
+
+    /// Returns true if `RUSTFLAGS="-C target-cpu=native"` is advised
+    #[must_use]
+    pub const fn wants_native_cpu(self) -> bool {
+        matches!(self, Self::HighThroughputWeb)
+    }
+}
+
+impl fmt::Display for UseCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HighThroughputWeb => write!(f, "high-throughput web"),
+            Self::SecuritySensitive => write!(f, "security-sensitive"),
+            Self::Cryptographic => write!(f, "cryptographic"),
+            Self::Embedded => write!(f, "embedded"),
+            Self::General => write!(f, "general"),
+        }
+    }
+}
+
+macro_rules! cargo_line {
+    () => {
+        cargo_line!("")
+    };
+    ($features:literal) => {
+        concat!(
+            "domain-key = { version = \"",
+            env!("CARGO_PKG_VERSION_MAJOR"),
+            ".",
+            env!("CARGO_PKG_VERSION_MINOR"),
+            "\"",
+            $features,
+            " }"
+        )
+    };
+}
+
+/// Get a ready-to-paste `Cargo.toml` dependency line for a use case
+///
+/// The returned snippet is tailored to the use case and, when CPU-specific
+/// optimizations matter, includes a comment line advising the `RUSTFLAGS`
+/// to build with.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::features::{cargo_snippet, UseCase};
+///
+/// let snippet = cargo_snippet(UseCase::HighThroughputWeb);
+/// assert!(snippet.starts_with("domain-key = { version = \""));
+/// assert!(snippet.contains(r#"features = ["fast", "std", "serde"]"#));
+/// assert!(snippet.contains("target-cpu=native"));
+/// ```
+#[must_use]
+pub const fn cargo_snippet(use_case: UseCase) -> &'static str {
+    match use_case {
+        UseCase::HighThroughputWeb => concat!(
+            cargo_line!(", features = [\"fast\", \"std\", \"serde\"]"),
+            "\n# Build with RUSTFLAGS=\"-C target-cpu=native\" so GxHash can use AES-NI"
+        ),
+        UseCase::SecuritySensitive => {
+            cargo_line!(", features = [\"secure\", \"std\", \"serde\"]")
+        }
+        UseCase::Cryptographic => cargo_line!(", features = [\"crypto\", \"std\", \"serde\"]"),
+        UseCase::Embedded => {
+            cargo_line!(", default-features = false, features = [\"no_std\"]")
+        }
+        UseCase::General => cargo_line!(),
+    }
+}
+
+/// Check the current build against the recommendation for a use case
+///
+/// Returns a list of warnings describing where the current build contradicts
+/// the recommended configuration. An empty list means the build matches.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::features::{check_build_for, UseCase};
+///
+/// for warning in check_build_for(UseCase::SecuritySensitive) {
+///     println!("warning: {warning}");
+/// }
+/// ```
+#[must_use]
+pub fn check_build_for(use_case: UseCase) -> Vec<&'static str> {
+    let mut warnings = Vec::new();
+    let category = hash_category();
+
+    match use_case {
+        UseCase::HighThroughputWeb => {
+            if category != HashCategory::UltraFast {
+                warnings
+                    .push("GxHash is not active; enable 'fast' and build with target-cpu=native");
+            }
+        }
+        UseCase::SecuritySensitive => {
+            if category != HashCategory::Secure {
+                warnings
+                    .push("AHash is not the active hash; enable only the 'secure' hash feature");
+            }
+        }
+        UseCase::Cryptographic => {
+            if category != HashCategory::Cryptographic {
+                warnings
+                    .push("Blake3 is not the active hash; enable only the 'crypto' hash feature");
+            }
+        }
+        UseCase::Embedded => {
+            if has_std() {
+                warnings.push("Standard library is enabled; disable default features for no_std");
+            }
+        }
+        UseCase::General => {}
+    }
+
+    if !matches!(use_case, UseCase::Embedded) && !has_std() {
+        warnings.push("Standard library support is disabled");
+    }
+
+    warnings
+}
+
 /// Get recommendations based on current configuration
 #[must_use]
 pub fn analyze_current_configuration() -> ConfigurationAnalysis {
@@ -738,6 +1050,61 @@ pub fn configuration_summary() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_hash_str_matches_key_hash() {
+        use crate::domain::DefaultDomain;
+
+        let key = Key::<DefaultDomain>::new("  Mixed_Case ").unwrap();
+        assert_eq!(hash_str::<DefaultDomain>("  Mixed_Case "), key.hash());
+        assert_eq!(hash_str::<DefaultDomain>("mixed_case"), key.hash());
+        assert_ne!(hash_str::<DefaultDomain>("other"), key.hash());
+    }
+
+    #[test]
+    fn test_hash_str_applies_canonicalize() {
+        use crate::error::KeyParseError;
+
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct TicketDomain;
+        impl KeyDomain for TicketDomain {
+            const DOMAIN_NAME: &'static str = "ticket";
+            fn canonicalize(input: Cow<'_, str>) -> Result<Cow<'_, str>, KeyParseError> {
+                let digits = input.strip_prefix("t-").unwrap_or(&input);
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(KeyParseError::domain_error(
+                        "ticket",
+                        "expected a ticket number",
+                    ));
+                }
+                if input.starts_with("t-") {
+                    Ok(input)
+                } else {
+                    Ok(Cow::Owned(format!("t-{digits}")))
+                }
+            }
+        }
+
+        let key = Key::<TicketDomain>::new("42").unwrap();
+        assert_eq!(key.as_str(), "t-42");
+        assert_eq!(hash_str::<TicketDomain>("42"), key.hash());
+        assert_eq!(hash_str::<TicketDomain>(" T-42 "), key.hash());
+        assert_ne!(hash_str::<TicketDomain>("43"), key.hash());
+    }
+
+    #[cfg(all(feature = "secure", feature = "std", not(feature = "fast")))]
+    #[test]
+    fn test_seeded_hash() {
+        let a = seeded_hash([1, 2, 3, 4], b"tenant");
+        let b = seeded_hash([5, 6, 7, 8], b"tenant");
+        assert_ne!(a, b);
+        assert_eq!(a, seeded_hash([1, 2, 3, 4], b"tenant"));
+
+        // Within a run every key uses the same process seed
+        let seed = hash_seed();
+        assert_eq!(hash_seed(), seed);
+        assert_eq!(set_hash_seed([0; 4]), Err([0; 4]));
+    }
+
     #[test]
     fn test_feature_detection() {
         // These tests verify that feature detection works
@@ -754,6 +1121,19 @@ mod tests {
         assert!(!info.hash_algorithm.is_empty());
     }
 
+    #[test]
+    fn test_active_hash_runtime() {
+        let runtime = active_hash_runtime();
+        assert!(!runtime.is_empty());
+
+        #[cfg(not(feature = "fast"))]
+        assert_eq!(runtime, hash_algorithm());
+
+        // The test binary runs on the build machine, so detection must agree
+        #[cfg(all(feature = "fast", feature = "std"))]
+        assert_eq!(runtime, hash_algorithm());
+    }
+
     #[test]
     fn test_performance_info_display() {
         let info = performance_info();
@@ -790,6 +1170,41 @@ mod tests {
         // Minimal can be empty
     }
 
+    #[test]
+    fn test_cargo_snippets() {
+        let cases = [
+            UseCase::HighThroughputWeb,
+            UseCase::SecuritySensitive,
+            UseCase::Cryptographic,
+            UseCase::Embedded,
+            UseCase::General,
+        ];
+
+        for case in cases {
+            let snippet = cargo_snippet(case);
+            assert!(snippet.starts_with("domain-key = { version = \"0."));
+            for feature in case.features() {
+                if case != UseCase::General {
+                    assert!(snippet.contains(&format!("\"{feature}\"")));
+                }
+            }
+            assert_eq!(
+                snippet.contains("target-cpu=native"),
+                case.wants_native_cpu()
+            );
+        }
+
+        assert!(cargo_snippet(UseCase::Embedded).contains("default-features = false"));
+    }
+
+    #[test]
+    fn test_check_build_for() {
+        assert!(check_build_for(UseCase::General).is_empty() || !has_std());
+
+        #[cfg(feature = "std")]
+        assert!(!check_build_for(UseCase::Embedded).is_empty());
+    }
+
     #[test]
     fn test_configuration_analysis() {
         let analysis = analyze_current_configuration();
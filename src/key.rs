@@ -3,15 +3,19 @@
 //! This module contains the main `Key<T>` structure and its implementation,
 //! providing high-performance, type-safe key handling with extensive optimizations.
 
+use core::borrow::Borrow;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+use core::ops::Add;
 use core::str::FromStr;
 
 #[cfg(not(feature = "std"))]
 use alloc::borrow::Cow;
 #[cfg(not(feature = "std"))]
 use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use std::borrow::Cow;
@@ -21,9 +25,39 @@ use serde::{Deserialize, Serialize};
 
 use smartstring::alias::String as SmartString;
 
-use crate::domain::KeyDomain;
+#[cfg(all(feature = "shared", not(feature = "std")))]
+use alloc::sync::Arc;
+#[cfg(all(feature = "shared", feature = "std"))]
+use std::sync::Arc;
+
+/// Backing storage for key content
+///
+/// `SmartString` by default, keeping short keys inline. With the `shared`
+/// feature keys are backed by `Arc<str>`, so cloning is a reference count
+/// increment instead of a copy of heap-allocated content.
+#[cfg(not(feature = "shared"))]
+type Storage = SmartString;
+#[cfg(feature = "shared")]
+type Storage = Arc<str>;
+
+/// Converts a `SmartString` built by the utils helpers into key storage
+#[inline]
+#[cfg_attr(feature = "shared", allow(clippy::needless_pass_by_value))]
+fn into_storage(s: SmartString) -> Storage {
+    #[cfg(not(feature = "shared"))]
+    {
+        s
+    }
+    #[cfg(feature = "shared")]
+    {
+        Arc::from(s.as_str())
+    }
+}
+
+use crate::domain::{DomainInfo, KeyDomain, SubdomainOf};
 use crate::error::KeyParseError;
 use crate::utils;
+use crate::validation::ValidationBudget;
 
 // ============================================================================
 // CONSTANTS
@@ -67,15 +101,76 @@ impl<'a> Iterator for SplitIterator<'a> {
 }
 
 // ============================================================================
-// FAST CHARACTER VALIDATION
+// PART POSITION CACHE
 // ============================================================================
 
-/// Fast character validation function
-#[inline(always)]
-#[allow(clippy::inline_always)]
-const fn is_ascii_allowed_fast(c: char) -> bool {
-    // Simple lookup for common ASCII characters
-    matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' | '.')
+/// Delimiter positions recorded for `FREQUENTLY_SPLIT` domains
+///
+/// Holds the byte positions of the domain's default separator, recorded once
+/// when the key is created and shared by clones. Every other domain stores
+/// nothing here. It never takes part in comparisons, ordering or hashing.
+///
+/// The positions are recorded eagerly rather than on first use: a lazy cell
+/// would give `Key` interior mutability, which trips
+/// `clippy::mutable_key_type` for every map keyed by `Key`.
+#[derive(Clone, Default)]
+struct PartPositions(Option<PositionList>);
+
+// A thin pointer keeps the field at 8 bytes for domains that never split
+#[cfg(feature = "std")]
+type PositionList = std::sync::Arc<Vec<u32>>;
+#[cfg(not(feature = "std"))]
+type PositionList = alloc::sync::Arc<Vec<u32>>;
+
+impl PartPositions {
+    /// Records the default separator positions of `s` if `T` splits often
+    #[allow(clippy::cast_possible_truncation)]
+    fn record<T: KeyDomain>(s: &str) -> Self {
+        if !T::FREQUENTLY_SPLIT {
+            return Self(None);
+        }
+        // Key lengths fit in `u32`, so positions do too
+        let positions = s
+            .match_indices(T::default_separator())
+            .map(|(pos, _)| pos as u32);
+        Self(Some(PositionList::new(positions.collect())))
+    }
+
+    /// Returns the recorded positions if they were recorded for `delimiter`
+    fn get<T: KeyDomain>(&self, delimiter: char) -> Option<&[u32]> {
+        self.0
+            .as_deref()
+            .filter(|_| delimiter == T::default_separator())
+            .map(Vec::as_slice)
+    }
+}
+
+impl fmt::Debug for PartPositions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.as_deref().map_or(&[][..], Vec::as_slice))
+            .finish()
+    }
+}
+
+impl PartialEq for PartPositions {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PartPositions {}
+
+impl PartialOrd for PartPositions {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PartPositions {
+    fn cmp(&self, _other: &Self) -> core::cmp::Ordering {
+        core::cmp::Ordering::Equal
+    }
 }
 
 // ============================================================================
@@ -90,7 +185,7 @@ const fn is_ascii_allowed_fast(c: char) -> bool {
 ///
 /// # Performance Characteristics
 ///
-/// - **Memory Layout**: 32 bytes total (fits in single cache line)
+/// - **Memory Layout**: 48 bytes total (fits in single cache line)
 /// - **Hash Access**: O(1) via pre-computed hash
 /// - **Length Access**: O(1) via cached length field
 /// - **String Access**: Direct reference to internal storage
@@ -103,10 +198,10 @@ const fn is_ascii_allowed_fast(c: char) -> bool {
 /// # Memory Layout
 ///
 /// ```text
-/// Key<T> struct (32 bytes, cache-line friendly):
-/// ┌─────────────────────┬──────────┬─────────┬─────────────┐
-/// │ SmartString (24B)   │ hash (8B)│ len (4B)│ marker (0B) │
-/// └─────────────────────┴──────────┴─────────┴─────────────┘
+/// Key<T> struct (48 bytes with padding, cache-line friendly):
+/// ┌─────────────────────┬──────────┬─────────┬───────────┬─────────────┐
+/// │ SmartString (24B)   │ hash (8B)│ len (4B)│ parts (8B)│ marker (0B) │
+/// └─────────────────────┴──────────┴─────────┴───────────┴─────────────┘
 /// ```
 ///
 /// Keys use `SmartString` which stores strings up to 23 bytes inline on the stack,
@@ -134,10 +229,10 @@ const fn is_ascii_allowed_fast(c: char) -> bool {
 /// assert_eq!(key.len(), 8);
 /// # Ok::<(), domain_key::KeyParseError>(())
 /// ```
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
 pub struct Key<T: KeyDomain> {
-    /// Internal string storage using `SmartString` for optimal memory usage
-    inner: SmartString,
+    /// Internal string storage (`SmartString`, or `Arc<str>` with `shared`)
+    inner: Storage,
 
     /// Pre-computed hash value for O(1) hash operations
     ///
@@ -153,6 +248,11 @@ pub struct Key<T: KeyDomain> {
     /// improvement in hot paths.
     length: u32,
 
+    /// Default separator positions for `FREQUENTLY_SPLIT` domains
+    ///
+    /// Stays empty for every other domain and is ignored by comparisons.
+    parts: PartPositions,
+
     /// Zero-sized type marker for compile-time type safety
     ///
     /// This field provides compile-time type safety without any runtime
@@ -173,17 +273,43 @@ impl<T: KeyDomain> Clone for Key<T> {
             inner: self.inner.clone(),
             hash: self.hash,
             length: self.length,
+            parts: self.parts.clone(),
             _marker: PhantomData,
         }
     }
 }
 
+// Manual Debug implementation so redacted domains never print their content
+impl<T: KeyDomain> fmt::Debug for Key<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if T::REDACT_IN_DISPLAY {
+            return f
+                .debug_tuple("Key")
+                .field(&format_args!("{}", self.redacted()))
+                .finish();
+        }
+        f.debug_struct("Key")
+            .field("inner", &self.inner)
+            .field("hash", &self.hash)
+            .field("length", &self.length)
+            .field("parts", &self.parts)
+            .field("_marker", &self._marker)
+            .finish()
+    }
+}
+
 // Manual Hash implementation using cached hash for maximum performance
 impl<T: KeyDomain> Hash for Key<T> {
     /// O(1) hash implementation using pre-computed hash
     ///
     /// This is significantly faster than re-hashing the string content
     /// every time the key is used in hash-based collections.
+    ///
+    /// Only the cached `u64` is written to the hasher, so the map's hasher
+    /// never sees the key bytes. This assumes a hasher that passes the value
+    /// through or mixes it cheaply. When a custom `BuildHasher` must process
+    /// the key content itself, wrap keys in [`RehashKey`] instead. For lookups
+    /// by `&str`, use [`KeyMap`] or [`RehashKey`].
     #[inline(always)]
     #[allow(clippy::inline_always)]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -203,7 +329,7 @@ impl<T: KeyDomain> Serialize for Key<T> {
     where
         S: serde::Serializer,
     {
-        self.inner.serialize(serializer)
+        self.as_str().serialize(serializer)
     }
 }
 
@@ -213,22 +339,43 @@ impl<'de, T: KeyDomain> Deserialize<'de> for Key<T> {
     ///
     /// This implementation chooses the optimal deserialization strategy
     /// based on the format (human-readable vs binary) for best performance.
+    /// Borrowed, transient and owned strings are all accepted, so keys work
+    /// as map keys and with escaped or streamed input.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
             // For human-readable formats (JSON, YAML), use zero-copy when possible
-            let s = <&str>::deserialize(deserializer)?;
-            Key::new(s).map_err(|e| serde::de::Error::custom(e.to_string()))
+            deserializer.deserialize_str(KeyVisitor(PhantomData))
         } else {
-            // For binary formats, deserialize as owned string
-            let s = String::deserialize(deserializer)?;
-            Key::from_string(s).map_err(|e| serde::de::Error::custom(e.to_string()))
+            // For binary formats, prefer an owned string to reuse its allocation
+            deserializer.deserialize_string(KeyVisitor(PhantomData))
         }
     }
 }
 
+/// Visitor that validates any string form into a key
+#[cfg(feature = "serde")]
+struct KeyVisitor<T: KeyDomain>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T: KeyDomain> serde::de::Visitor<'_> for KeyVisitor<T> {
+    type Value = Key<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a valid {} key string", T::DOMAIN_NAME)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Key::new(value).map_err(E::custom)
+    }
+
+    fn visit_string<E: serde::de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Key::from_string(value).map_err(E::custom)
+    }
+}
+
 // ============================================================================
 // KEY IMPLEMENTATION - CORE METHODS
 // ============================================================================
@@ -295,25 +442,14 @@ impl<T: KeyDomain> Key<T> {
         // Step 1: Common validation (length, characters, structure)
         Self::validate_common::<T>(key)?;
 
-        // Step 2: Normalization (trimming, lowercasing, domain-specific)
-        let normalized = Self::normalize::<T>(key);
+        // Step 2: Common normalization (trimming, lowercasing)
+        let common = Self::normalize_common::<T>(key);
 
-        // Step 3: Domain-specific validation
-        T::validate_domain_rules(&normalized).map_err(Self::fix_domain_error)?;
-
-        // Step 4: Hash computation and storage
-        let hash = Self::compute_hash(&normalized);
-        let length = u32::try_from(normalized.len()).map_err(|_| KeyParseError::TooLong {
-            max_length: u32::MAX as usize,
-            actual_length: normalized.len(),
-        })?;
+        // Step 3: Domain-specific normalization and validation
+        let normalized = T::canonicalize(common).map_err(Self::fix_domain_error)?;
 
-        Ok(Self {
-            inner: SmartString::from(normalized.as_ref()),
-            hash,
-            length,
-            _marker: PhantomData,
-        })
+        // Step 4: Hash computation, hash-aware validation and storage
+        Self::finalize(Storage::from(normalized.as_ref()))
     }
 
     /// Creates a new key from an owned String with optimized handling
@@ -351,23 +487,98 @@ impl<T: KeyDomain> Key<T> {
         Self::validate_common::<T>(&key)?;
 
         // Normalize efficiently, reusing allocation when possible
-        let normalized = Self::normalize_owned::<T>(key);
+        let common = Self::normalize_owned::<T>(key);
 
-        // Domain validation
-        T::validate_domain_rules(&normalized).map_err(Self::fix_domain_error)?;
+        // Domain normalization and validation
+        let normalized = T::canonicalize(Cow::Owned(common))
+            .map_err(Self::fix_domain_error)?
+            .into_owned();
 
-        let hash = Self::compute_hash(&normalized);
-        let length = u32::try_from(normalized.len()).map_err(|_| KeyParseError::TooLong {
-            max_length: u32::MAX as usize,
-            actual_length: normalized.len(),
-        })?;
+        Self::finalize(Storage::from(normalized))
+    }
 
-        Ok(Self {
-            inner: SmartString::from(normalized),
-            hash,
-            length,
-            _marker: PhantomData,
-        })
+    /// Creates a key, reusing the cached hash of `source` when possible
+    ///
+    /// When `key` is exactly the content of `source`, the result is a clone
+    /// of `source` and neither validation nor hashing is repeated. Any other
+    /// input goes through [`Key::new`] as usual, so the shortcut can never
+    /// produce a key that `new` would reject.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - String to create the key from
+    /// * `source` - Existing key whose hash may be reused
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if `key` differs from `source` and fails
+    /// validation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let source = TestKey::new("user_profile")?;
+    /// let same = TestKey::from_parts_trusted("user_profile", &source)?;
+    /// assert_eq!(same.hash(), source.hash());
+    ///
+    /// let other = TestKey::from_parts_trusted("User_Settings", &source)?;
+    /// assert_eq!(other.as_str(), "user_settings");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn from_parts_trusted(key: &str, source: &Self) -> Result<Self, KeyParseError> {
+        match source.with_hash_cached_from(key) {
+            Some(same) => Ok(same),
+            None => Self::new(key),
+        }
+    }
+
+    /// Creates a new key from a `Cow<str>`
+    ///
+    /// Owned input goes through [`Key::from_string`] so its allocation can be
+    /// reused, while borrowed input takes the regular [`Key::new`] path.
+    /// This avoids an extra allocation in pipelines that already produce
+    /// `Cow` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Borrowed or owned key string
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the key fails validation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let borrowed = TestKey::from_cow(Cow::Borrowed("test_key"))?;
+    /// let owned = TestKey::from_cow(Cow::Owned("test_key".to_string()))?;
+    /// assert_eq!(borrowed, owned);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn from_cow(input: Cow<'_, str>) -> Result<Self, KeyParseError> {
+        match input {
+            Cow::Borrowed(key) => Self::new(key),
+            Cow::Owned(key) => Self::from_string(key),
+        }
     }
 
     /// Create a key from multiple parts separated by a delimiter
@@ -403,18 +614,46 @@ impl<T: KeyDomain> Key<T> {
     /// ```
     /// # Errors
     ///
-    /// Returns `KeyParseError` if the constructed key fails validation
+    /// Returns `KeyParseError` if the constructed key fails validation, or
+    /// `InvalidStructure` if the delimiter contains characters the domain
+    /// does not allow
     pub fn from_parts(parts: &[&str], delimiter: &str) -> Result<Self, KeyParseError> {
         if parts.is_empty() {
             return Err(KeyParseError::Empty);
         }
 
+        if !delimiter.chars().all(T::allowed_characters) {
+            return Err(KeyParseError::InvalidStructure {
+                reason: "delimiter not allowed for domain",
+            });
+        }
+
         if parts.iter().any(|part| part.is_empty()) {
             return Err(KeyParseError::InvalidStructure {
                 reason: "Parts cannot contain empty strings",
             });
         }
 
+        // Check the length before allocating so oversized input fails cheaply
+        let total_length = delimiter
+            .len()
+            .checked_mul(parts.len() - 1)
+            .and_then(|separators| {
+                parts
+                    .iter()
+                    .try_fold(separators, |total, part| total.checked_add(part.len()))
+            })
+            .unwrap_or(usize::MAX);
+        let outer_whitespace = (parts[0].len() - parts[0].trim_start().len())
+            + (parts[parts.len() - 1].len() - parts[parts.len() - 1].trim_end().len());
+        let trimmed_length = total_length.saturating_sub(outer_whitespace);
+        if trimmed_length > T::MAX_LENGTH {
+            return Err(KeyParseError::TooLong {
+                max_length: T::MAX_LENGTH,
+                actual_length: trimmed_length,
+            });
+        }
+
         let joined = parts.join(delimiter);
 
         if joined.is_empty() {
@@ -424,10 +663,14 @@ impl<T: KeyDomain> Key<T> {
         Self::from_string(joined)
     }
 
-    /// Try to create a key from multiple parts, returning None on failure
+    /// Create a key from multiple parts joined by the domain's separator
     ///
-    /// This is a convenience method for when you want to handle validation
-    /// failures by ignoring invalid keys rather than handling errors.
+    /// Equivalent to [`Key::from_parts`] with
+    /// [`KeyDomain::default_separator`] as the delimiter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the constructed key fails validation
     ///
     /// # Examples
     ///
@@ -441,29 +684,69 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let valid = TestKey::try_from_parts(&["user", "123"], "_").unwrap();
-    /// let invalid = TestKey::try_from_parts(&["", ""], "_"); // Returns None
-    /// assert!(invalid.is_none());
+    /// let key = TestKey::from_parts_default(&["user", "123", "profile"])?;
+    /// assert_eq!(key.as_str(), "user_123_profile");
+    /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    #[must_use]
-    pub fn try_from_parts(parts: &[&str], delimiter: &str) -> Option<Self> {
-        Self::from_parts(parts, delimiter).ok()
+    pub fn from_parts_default(parts: &[&str]) -> Result<Self, KeyParseError> {
+        let mut buf = [0u8; 4];
+        Self::from_parts(parts, T::default_separator().encode_utf8(&mut buf))
     }
 
-    /// Creates a key from a static string without runtime validation
+    /// Creates a key from `s`, clamping it to the domain's `MAX_LENGTH`
     ///
-    /// # Safety
+    /// Input longer than `MAX_LENGTH` bytes is cut at the last character
+    /// boundary that fits, and any trailing characters rejected by
+    /// [`KeyDomain::allowed_end_character`] are trimmed from the cut. The
+    /// truncation happens before normalization, so leading whitespace
+    /// counts towards the limit. The clamped string is then validated as
+    /// usual and may still fail.
     ///
-    /// The caller must ensure that the static string follows all validation
-    /// rules for the domain. Invalid keys created this way may cause
-    /// undefined behavior in other parts of the system that assume all
-    /// keys are valid.
+    /// # Arguments
     ///
-    /// Use the `static_key!` macro instead for compile-time checked static keys.
+    /// * `s` - Input that may exceed the domain's maximum length
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `key` - A static string literal that represents a valid key
+    /// Returns `KeyParseError` if the clamped string is not a valid key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct SlugDomain;
+    /// impl KeyDomain for SlugDomain {
+    ///     const DOMAIN_NAME: &'static str = "slug";
+    ///     const MAX_LENGTH: usize = 12;
+    /// }
+    /// type SlugKey = Key<SlugDomain>;
+    ///
+    /// let slug = SlugKey::truncate_to_fit("release_notes_for_v2")?;
+    /// assert_eq!(slug.as_str(), "release_note");
+    ///
+    /// // A cut that ends on a separator drops it
+    /// let slug = SlugKey::truncate_to_fit("release_new_features")?;
+    /// assert_eq!(slug.as_str(), "release_new");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn truncate_to_fit(s: &str) -> Result<Self, KeyParseError> {
+        if s.len() <= T::MAX_LENGTH {
+            return Self::new(s);
+        }
+
+        let mut end = T::MAX_LENGTH;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        Self::new(s[..end].trim_end_matches(|c| !T::allowed_end_character(c)))
+    }
+
+    /// Try to create a key from multiple parts, returning None on failure
+    ///
+    /// This is a convenience method for when you want to handle validation
+    /// failures by ignoring invalid keys rather than handling errors.
     ///
     /// # Examples
     ///
@@ -477,37 +760,33 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// // SAFETY: "static_key" is a valid key for TestDomain
-    /// let key = TestKey::from_static_unchecked("static_key");
-    /// assert_eq!(key.as_str(), "static_key");
+    /// let valid = TestKey::try_from_parts(&["user", "123"], "_").unwrap();
+    /// let invalid = TestKey::try_from_parts(&["", ""], "_"); // Returns None
+    /// assert!(invalid.is_none());
     /// ```
     #[must_use]
-    pub fn from_static_unchecked(key: &'static str) -> Self {
-        let hash = Self::compute_hash(key);
-        #[allow(clippy::cast_possible_truncation)]
-        let length = key.len() as u32;
-
-        Self {
-            inner: SmartString::from(key),
-            hash,
-            length,
-            _marker: PhantomData,
-        }
+    pub fn try_from_parts(parts: &[&str], delimiter: &str) -> Option<Self> {
+        Self::from_parts(parts, delimiter).ok()
     }
 
-    /// Creates a key from a static string with validation
+    /// Create a key by joining already validated keys with a separator
     ///
-    /// This is a safer alternative to `from_static_unchecked` that validates
-    /// the key at runtime. The validation cost is paid once, and subsequent
-    /// uses of the key are as fast as the unchecked version.
+    /// Since every input key is already valid, only the total length and the
+    /// seams around each separator are checked before the domain rules run
+    /// on the combined key. The result is built with a single pre-sized
+    /// allocation. Case-insensitive domains lowercase `sep` first, so the
+    /// result equals what [`Key::new`] gives for the joined string.
     ///
     /// # Arguments
     ///
-    /// * `key` - A static string literal to validate and convert
+    /// * `keys` - Keys to join, in order
+    /// * `sep` - Separator to insert between consecutive keys
     ///
     /// # Errors
     ///
-    /// Returns `KeyParseError` if the static key fails validation
+    /// Returns `KeyParseError` if `keys` is empty, the combined key is too
+    /// long, the separator is not allowed by the domain, or the combined key
+    /// fails the domain rules
     ///
     /// # Examples
     ///
@@ -521,28 +800,79 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::try_from_static("static_key")?;
-    /// assert_eq!(key.as_str(), "static_key");
-    ///
-    /// let invalid = TestKey::try_from_static("");
-    /// assert!(invalid.is_err());
+    /// let tenant = TestKey::new("acme")?;
+    /// let user = TestKey::new("user_42")?;
+    /// let key = TestKey::concat_keys(&[&tenant, &user], '.')?;
+    /// assert_eq!(key.as_str(), "acme.user_42");
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    /// # Errors
-    ///
-    /// Returns `KeyParseError` if the constructed key fails validation
-    pub fn try_from_static(key: &'static str) -> Result<Self, KeyParseError> {
-        // First validate that the key is correct
-        Self::new(key)?;
+    pub fn concat_keys(keys: &[&Self], sep: char) -> Result<Self, KeyParseError> {
+        let Some((first, rest)) = keys.split_first() else {
+            return Err(KeyParseError::Empty);
+        };
+        let sep = if T::CASE_INSENSITIVE {
+            sep.to_ascii_lowercase()
+        } else {
+            sep
+        };
+
+        let total_length =
+            keys.iter().map(|key| key.len()).sum::<usize>() + rest.len() * sep.len_utf8();
+        if total_length > T::MAX_LENGTH {
+            return Err(KeyParseError::TooLong {
+                max_length: T::MAX_LENGTH,
+                actual_length: total_length,
+            });
+        }
+
+        if !rest.is_empty() && !T::allowed_characters(sep) {
+            return Err(KeyParseError::InvalidCharacter {
+                character: sep,
+                position: first.len(),
+                expected: Some("allowed by domain"),
+            });
+        }
+
+        let mut combined = String::with_capacity(total_length);
+        combined.push_str(first.as_str());
+        for key in rest {
+            if let (Some(prev), Some(next)) = (combined.chars().next_back(), key.chars().next()) {
+                if !T::allowed_consecutive_characters(prev, sep)
+                    || !T::allowed_consecutive_characters(sep, next)
+                {
+                    return Err(KeyParseError::InvalidStructure {
+                        reason: "consecutive characters not allowed",
+                    });
+                }
+            }
+            combined.push(sep);
+            combined.push_str(key.as_str());
+        }
 
-        // We just validated that the key is correct above
-        Ok(Self::from_static_unchecked(key))
+        let inner = match Self::check_domain_rules(&combined)? {
+            Cow::Owned(canonical) => Storage::from(canonical),
+            Cow::Borrowed(_) => Storage::from(combined),
+        };
+        Self::finalize(inner)
     }
 
-    /// Try to create a key, returning None on validation failure
+    /// Joins this key and `other` with a separator
     ///
-    /// This is a convenience method for when you want to handle validation
-    /// failures by ignoring invalid keys rather than handling errors.
+    /// Shorthand for [`Key::concat_keys`] with two keys: the buffer is
+    /// sized once as `self.len() + other.len() + 1`, and the combined key is
+    /// checked against `MAX_LENGTH`, the separator seams and the domain
+    /// rules before its hash is computed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Key to append
+    /// * `separator` - Character placed between the two keys
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the combined key is too long, the
+    /// separator is not allowed, or the seams break the consecutive
+    /// character rules
     ///
     /// # Examples
     ///
@@ -556,53 +886,77 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let valid = TestKey::try_new("valid_key").unwrap();
-    /// let invalid = TestKey::try_new(""); // Returns None
-    /// assert!(invalid.is_none());
+    /// let tenant = TestKey::new("acme")?;
+    /// let user = TestKey::new("alice")?;
+    /// assert_eq!(tenant.concat(&user, '_')?.as_str(), "acme_alice");
+    /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    #[inline]
-    pub fn try_new(key: impl AsRef<str>) -> Option<Self> {
-        Self::new(key).ok()
+    pub fn concat(&self, other: &Self, separator: char) -> Result<Self, KeyParseError> {
+        Self::concat_keys(&[self, other], separator)
     }
-}
-
-// ============================================================================
-// KEY IMPLEMENTATION - ACCESSOR METHODS
-// ============================================================================
 
-impl<T: KeyDomain> Key<T> {
-    /// Returns the key as a string slice
+    /// Joins owned keys with a separator
     ///
-    /// This is the primary way to access the string content of a key.
-    /// The returned reference is valid for the lifetime of the key.
+    /// Accepts any iterator of keys, e.g. a `Vec<Key<T>>`, and behaves like
+    /// [`Key::concat_keys`]: capacity comes from the cached lengths and the
+    /// combined key is validated once.
     ///
-    /// # Examples
+    /// # Arguments
+    ///
+    /// * `keys` - Keys to join, in order
+    /// * `separator` - Character placed between consecutive keys
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::Empty` for an empty iterator, and the same
+    /// errors as [`Key::concat_keys`] otherwise
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use domain_key::{Key, KeyDomain};
+    /// use domain_key::{Key, PathDomain};
     ///
-    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-    /// struct TestDomain;
-    /// impl KeyDomain for TestDomain {
-    ///     const DOMAIN_NAME: &'static str = "test";
-    /// }
-    /// type TestKey = Key<TestDomain>;
+    /// type PathKey = Key<PathDomain>;
     ///
-    /// let key = TestKey::new("example")?;
-    /// assert_eq!(key.as_str(), "example");
+    /// let parts = vec![PathKey::new("tenant")?, PathKey::new("users")?];
+    /// assert_eq!(PathKey::join(parts, '/')?.as_str(), "tenant/users");
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    #[inline(always)]
-    #[allow(clippy::inline_always)]
-    #[must_use]
-    pub fn as_str(&self) -> &str {
-        &self.inner
+    pub fn join<I: IntoIterator<Item = Self>>(
+        keys: I,
+        separator: char,
+    ) -> Result<Self, KeyParseError> {
+        let keys: Vec<Self> = keys.into_iter().collect();
+        let refs: Vec<&Self> = keys.iter().collect();
+        Self::concat_keys(&refs, separator)
     }
 
-    /// Returns the domain name for this key type
+    /// Creates a key from a static string without runtime validation
     ///
-    /// This is a compile-time constant that identifies which domain
-    /// this key belongs to.
+    /// # Safety
+    ///
+    /// The caller must ensure that the static string follows all validation
+    /// rules for the domain. Invalid keys created this way may cause
+    /// undefined behavior in other parts of the system that assume all
+    /// keys are valid.
+    ///
+    /// Use the `static_key!` macro instead for compile-time checked static keys.
+    ///
+    /// The string is still normalized (trimmed, lowercased for
+    /// case-insensitive domains, and passed through
+    /// [`KeyDomain::canonicalize`]), so it yields the same key as
+    /// [`Key::new`] would. Already-normalized input is used without an
+    /// intermediate allocation.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `canonicalize` rejects the string, since
+    /// the resulting key could never equal one built by [`Key::new`]. Release
+    /// builds keep the trimmed and lowercased form instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A static string literal that represents a valid key
     ///
     /// # Examples
     ///
@@ -610,26 +964,52 @@ impl<T: KeyDomain> Key<T> {
     /// use domain_key::{Key, KeyDomain};
     ///
     /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-    /// struct UserDomain;
-    /// impl KeyDomain for UserDomain {
-    ///     const DOMAIN_NAME: &'static str = "user";
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
     /// }
-    /// type UserKey = Key<UserDomain>;
+    /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = UserKey::new("john")?;
-    /// assert_eq!(key.domain(), "user");
-    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// // SAFETY: "static_key" is a valid key for TestDomain
+    /// let key = TestKey::from_static_unchecked("static_key");
+    /// assert_eq!(key.as_str(), "static_key");
     /// ```
-    #[inline(always)]
-    #[allow(clippy::inline_always, clippy::unused_self)]
     #[must_use]
-    pub const fn domain(&self) -> &'static str {
-        T::DOMAIN_NAME
+    pub fn from_static_unchecked(key: &'static str) -> Self {
+        let common = Self::normalize_common::<T>(key);
+        let canonical = T::canonicalize(Cow::Borrowed(&common));
+        debug_assert!(
+            canonical.is_ok(),
+            "from_static_unchecked: {key:?} is not a valid {} key",
+            T::DOMAIN_NAME
+        );
+        let normalized = canonical.unwrap_or(Cow::Borrowed(&common));
+        let hash = Self::compute_hash(&normalized);
+        #[allow(clippy::cast_possible_truncation)]
+        let length = normalized.len() as u32;
+
+        Self {
+            inner: Storage::from(normalized.as_ref()),
+            hash,
+            length,
+            parts: PartPositions::record::<T>(&normalized),
+            _marker: PhantomData,
+        }
     }
 
-    /// Returns the length of the key string
+    /// Creates a key from a static string with validation
+    ///
+    /// This is a safer alternative to `from_static_unchecked` that validates
+    /// the key at runtime. The validation cost is paid once, and subsequent
+    /// uses of the key are as fast as the unchecked version.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A static string literal to validate and convert
+    ///
+    /// # Errors
     ///
-    /// This is an O(1) operation using a cached length value.
+    /// Returns `KeyParseError` if the static key fails validation
     ///
     /// # Examples
     ///
@@ -643,22 +1023,24 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("example")?;
-    /// assert_eq!(key.len(), 7);
+    /// let key = TestKey::try_from_static("static_key")?;
+    /// assert_eq!(key.as_str(), "static_key");
+    ///
+    /// let invalid = TestKey::try_from_static("");
+    /// assert!(invalid.is_err());
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    #[inline(always)]
-    #[allow(clippy::inline_always)]
-    #[must_use]
-    pub fn len(&self) -> usize {
-        self.length as usize
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the constructed key fails validation
+    pub fn try_from_static(key: &'static str) -> Result<Self, KeyParseError> {
+        Self::new(key)
     }
 
-    /// Returns true if the key is empty (this should never happen for valid keys)
+    /// Try to create a key, returning None on validation failure
     ///
-    /// Since empty keys are rejected during validation, this method should
-    /// always return `false` for properly constructed keys. It's provided
-    /// for completeness and debugging purposes.
+    /// This is a convenience method for when you want to handle validation
+    /// failures by ignoring invalid keys rather than handling errors.
     ///
     /// # Examples
     ///
@@ -672,22 +1054,22 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("example")?;
-    /// assert!(!key.is_empty());
-    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// let valid = TestKey::try_new("valid_key").unwrap();
+    /// let invalid = TestKey::try_new(""); // Returns None
+    /// assert!(invalid.is_none());
     /// ```
-    #[inline(always)]
-    #[allow(clippy::inline_always)]
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.length == 0
+    #[inline]
+    pub fn try_new(key: impl AsRef<str>) -> Option<Self> {
+        Self::new(key).ok()
     }
 
-    /// Returns the cached hash value
+    /// Creates the domain's designated default key
     ///
-    /// This hash is computed once during key creation and cached for the
-    /// lifetime of the key. It's used internally for hash-based collections
-    /// and can be useful for custom hash-based data structures.
+    /// Returns `None` when the domain does not provide
+    /// [`KeyDomain::default_key`] or when the provided string fails
+    /// validation. This supports sentinel and placeholder patterns without
+    /// a blanket `Default` implementation, which would have to panic for
+    /// domains without a default.
     ///
     /// # Examples
     ///
@@ -698,34 +1080,35 @@ impl<T: KeyDomain> Key<T> {
     /// struct TestDomain;
     /// impl KeyDomain for TestDomain {
     ///     const DOMAIN_NAME: &'static str = "test";
+    ///
+    ///     fn default_key() -> Option<&'static str> {
+    ///         Some("unassigned")
+    ///     }
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key1 = TestKey::new("example")?;
-    /// let key2 = TestKey::new("example")?;
-    /// let key3 = TestKey::new("different")?;
-    ///
-    /// // Same keys have same hash
-    /// assert_eq!(key1.hash(), key2.hash());
-    /// // Different keys have different hashes (with high probability)
-    /// assert_ne!(key1.hash(), key3.hash());
-    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// let key = TestKey::domain_default().unwrap();
+    /// assert_eq!(key.as_str(), "unassigned");
     /// ```
-    #[inline(always)]
-    #[allow(clippy::inline_always)]
     #[must_use]
-    pub const fn hash(&self) -> u64 {
-        self.hash
+    pub fn domain_default() -> Option<Self> {
+        T::default_key().and_then(Self::try_new)
     }
 
-    /// Checks if this key starts with the given prefix
+    /// Creates a new key and reports whether normalization changed the input
     ///
-    /// This is a simple string prefix check that can be useful for
-    /// categorizing or filtering keys.
+    /// The returned flag is `true` when the stored key differs from `input`,
+    /// for example because of trimming, lowercasing or domain-specific
+    /// normalization. This lets callers warn about surprising normalization
+    /// at the point of creation without a second comparison pass.
     ///
     /// # Arguments
     ///
-    /// * `prefix` - The prefix string to check for
+    /// * `input` - String that will be normalized and validated
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the key fails validation
     ///
     /// # Examples
     ///
@@ -739,30 +1122,42 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("user_profile")?;
-    /// assert!(key.starts_with("user_"));
-    /// assert!(!key.starts_with("admin_"));
+    /// let (key, changed) = TestKey::new_checked("My_Key")?;
+    /// assert_eq!(key.as_str(), "my_key");
+    /// assert!(changed);
+    ///
+    /// let (_, changed) = TestKey::new_checked("my_key")?;
+    /// assert!(!changed);
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn starts_with(&self, prefix: &str) -> bool {
-        self.inner.starts_with(prefix)
+    pub fn new_checked(input: &str) -> Result<(Self, bool), KeyParseError> {
+        let key = Self::new_optimized(input)?;
+        let changed = key.as_str() != input;
+        Ok((key, changed))
     }
 
-    /// Checks if this key ends with the given suffix
+    /// Creates a new key, charging the work to a validation budget
     ///
-    /// This is a simple string suffix check that can be useful for
-    /// categorizing or filtering keys.
+    /// The input length and one validation pass are charged to `budget` before
+    /// any validation runs, so the total work spent on untrusted input stays
+    /// bounded even for domains with expensive custom validators. The budget
+    /// is charged whether or not validation then succeeds.
     ///
     /// # Arguments
     ///
-    /// * `suffix` - The suffix string to check for
+    /// * `key` - String-like input that will be normalized and validated
+    /// * `budget` - Budget shared across the keys created for one request
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::Custom` with code
+    /// [`ValidationBudget::EXCEEDED_CODE`] if the budget is exhausted, or any
+    /// validation error from the normal pipeline
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use domain_key::{Key, KeyDomain};
+    /// use domain_key::{Key, KeyDomain, ValidationBudget};
     ///
     /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
     /// struct TestDomain;
@@ -771,51 +1166,80 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("user_profile")?;
-    /// assert!(key.ends_with("_profile"));
-    /// assert!(!key.ends_with("_settings"));
-    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// let mut budget = ValidationBudget::new(16, 8);
+    /// assert!(TestKey::new_with_budget("user_1", &mut budget).is_ok());
+    /// assert!(TestKey::new_with_budget("a_much_longer_key", &mut budget).is_err());
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn ends_with(&self, suffix: &str) -> bool {
-        self.inner.ends_with(suffix)
+    pub fn new_with_budget(
+        key: impl AsRef<str>,
+        budget: &mut ValidationBudget,
+    ) -> Result<Self, KeyParseError> {
+        let key_str = key.as_ref();
+        budget.charge(key_str.len())?;
+        Self::new_optimized(key_str)
     }
 
-    /// Checks if this key contains the given substring
+    /// Creates a new key with a runtime maximum length override
     ///
-    /// This performs a substring search within the key.
+    /// The effective limit is the smaller of `T::MAX_LENGTH` and `max_length`,
+    /// so a deployment can tighten a domain's limit from configuration without
+    /// a recompile, but never loosen it. All other validation flows through
+    /// the normal creation pipeline.
     ///
     /// # Arguments
     ///
-    /// * `pattern` - The substring to search for
+    /// * `key` - String-like input that will be normalized and validated
+    /// * `max_length` - Runtime upper bound for the key length
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::TooLong` if the key exceeds the effective limit,
+    /// or any other validation error from the normal pipeline
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use domain_key::{Key, KeyDomain};
+    /// use domain_key::{Key, KeyDomain, KeyParseError};
     ///
     /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
     /// struct TestDomain;
     /// impl KeyDomain for TestDomain {
     ///     const DOMAIN_NAME: &'static str = "test";
+    ///     const MAX_LENGTH: usize = 32;
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("user_profile_settings")?;
-    /// assert!(key.contains("profile"));
-    /// assert!(!key.contains("admin"));
-    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// assert!(TestKey::new_bounded("short", 8).is_ok());
+    /// assert!(matches!(
+    ///     TestKey::new_bounded("too_long_key", 8),
+    ///     Err(KeyParseError::TooLong { max_length: 8, actual_length: 12 })
+    /// ));
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn contains(&self, pattern: &str) -> bool {
-        self.inner.contains(pattern)
+    pub fn new_bounded(key: impl AsRef<str>, max_length: usize) -> Result<Self, KeyParseError> {
+        let key_str = key.as_ref();
+        let limit = T::MAX_LENGTH.min(max_length);
+        let trimmed_len = key_str.trim().len();
+
+        if trimmed_len > limit {
+            return Err(KeyParseError::TooLong {
+                max_length: limit,
+                actual_length: trimmed_len,
+            });
+        }
+
+        Self::new_optimized(key_str)
     }
+}
 
-    /// Returns an iterator over the characters of the key
+// ============================================================================
+// KEY IMPLEMENTATION - ACCESSOR METHODS
+// ============================================================================
+
+impl<T: KeyDomain> Key<T> {
+    /// Returns the key as a string slice
     ///
-    /// This provides access to individual characters in the key string.
+    /// This is the primary way to access the string content of a key.
+    /// The returned reference is valid for the lifetime of the key.
     ///
     /// # Examples
     ///
@@ -829,22 +1253,22 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("abc")?;
-    /// let chars: Vec<char> = key.chars().collect();
-    /// assert_eq!(chars, vec!['a', 'b', 'c']);
+    /// let key = TestKey::new("example")?;
+    /// assert_eq!(key.as_str(), "example");
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    pub fn chars(&self) -> core::str::Chars<'_> {
-        self.inner.chars()
+    #[inline(always)]
+    #[allow(clippy::inline_always)]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.inner
     }
 
-    /// Splits the key by a delimiter and returns an iterator
+    /// Returns the key as a byte slice
     ///
-    /// This method provides consistent split functionality.
-    ///
-    /// # Arguments
-    ///
-    /// * `delimiter` - Character to split on
+    /// The bytes are the normalized UTF-8 content, identical to
+    /// `self.as_str().as_bytes()`, so keys can be used directly with
+    /// byte-oriented stores.
     ///
     /// # Examples
     ///
@@ -858,28 +1282,23 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("user_profile_settings")?;
-    /// let parts: Vec<&str> = key.split('_').collect();
-    /// assert_eq!(parts, vec!["user", "profile", "settings"]);
+    /// let key = TestKey::new("Example")?;
+    /// assert_eq!(key.as_bytes(), b"example");
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
+    #[inline]
     #[must_use]
-    pub fn split(&self, delimiter: char) -> SplitIterator<'_> {
-        SplitIterator::Cached(utils::new_split_cache(&self.inner, delimiter))
-    }
-
-    /// Split operation for consistent API
-    ///
-    /// This method provides the same functionality as `split()` but with explicit naming
-    /// for cases where caching behavior needs to be clear.
-    #[must_use]
-    pub fn split_cached(&self, delimiter: char) -> SplitCache<'_> {
-        utils::new_split_cache(&self.inner, delimiter)
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inner.as_bytes()
     }
 
-    /// Splits the key by a string delimiter and returns an iterator
+    /// Consumes the key and returns its content as a `String`
     ///
-    /// This method splits the key using a string pattern rather than a single character.
+    /// Keys of up to 23 bytes are stored inline and are always copied into a
+    /// freshly allocated `String`. Longer keys are heap-backed; `smartstring`
+    /// hands that buffer over without copying when its allocator support is
+    /// enabled and copies it otherwise. Either way no revalidation happens.
+    /// This is the same conversion as `String::from(key)`.
     ///
     /// # Examples
     ///
@@ -893,24 +1312,27 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("user-and-profile-and-settings")?;
-    /// let parts: Vec<&str> = key.split_str("-and-").collect();
-    /// assert_eq!(parts, vec!["user", "profile", "settings"]);
+    /// let key = TestKey::new("user_profile")?;
+    /// assert_eq!(key.into_string(), "user_profile");
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
+    #[inline]
     #[must_use]
-    pub fn split_str<'a>(&'a self, delimiter: &'a str) -> core::str::Split<'a, &'a str> {
-        self.inner.split(delimiter)
+    pub fn into_string(self) -> String {
+        #[cfg(not(feature = "shared"))]
+        {
+            self.inner.into()
+        }
+        #[cfg(feature = "shared")]
+        {
+            String::from(&*self.inner)
+        }
     }
 
-    /// Returns the key with a prefix if it doesn't already have it
-    ///
-    /// This method efficiently adds a prefix to a key if it doesn't already
-    /// start with that prefix.
-    ///
-    /// # Arguments
+    /// Returns the key content, even for redacted domains
     ///
-    /// * `prefix` - The prefix to ensure is present
+    /// Identical to [`Key::as_str`], but makes it explicit at the call site
+    /// that the raw value of a sensitive key is being used on purpose.
     ///
     /// # Examples
     ///
@@ -918,74 +1340,86 @@ impl<T: KeyDomain> Key<T> {
     /// use domain_key::{Key, KeyDomain};
     ///
     /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-    /// struct TestDomain;
-    /// impl KeyDomain for TestDomain {
-    ///     const DOMAIN_NAME: &'static str = "test";
+    /// struct EmailDomain;
+    /// impl KeyDomain for EmailDomain {
+    ///     const DOMAIN_NAME: &'static str = "email";
+    ///     const REDACT_IN_DISPLAY: bool = true;
     /// }
-    /// type TestKey = Key<TestDomain>;
-    ///
-    /// let key = TestKey::new("profile")?;
-    /// let prefixed = key.ensure_prefix("user_")?;
-    /// assert_eq!(prefixed.as_str(), "user_profile");
+    /// type EmailKey = Key<EmailDomain>;
     ///
-    /// // If prefix already exists, returns the same key
-    /// let already_prefixed = prefixed.ensure_prefix("user_")?;
-    /// assert_eq!(already_prefixed.as_str(), "user_profile");
+    /// let key = EmailKey::new("alice")?;
+    /// assert!(!format!("{key:?}").contains("alice"));
+    /// assert_eq!(key.reveal(), "alice");
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    /// # Errors
-    ///
-    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
-    pub fn ensure_prefix(&self, prefix: &str) -> Result<Self, KeyParseError> {
-        if self.starts_with(prefix) {
-            return Ok(self.clone());
-        }
-
-        let new_len = prefix.len() + self.len();
-        if new_len > T::MAX_LENGTH {
-            return Err(KeyParseError::TooLong {
-                max_length: T::MAX_LENGTH,
-                actual_length: new_len,
-            });
-        }
-
-        let result = utils::add_prefix_optimized(&self.inner, prefix, T::MAX_LENGTH);
-
-        // Quick validation of prefix only
-        for (i, c) in prefix.chars().enumerate() {
-            if !T::allowed_characters(c) {
-                return Err(KeyParseError::InvalidCharacter {
-                    character: c,
-                    position: i,
-                    expected: Some("allowed by domain"),
-                });
-            }
-        }
-
-        T::validate_domain_rules(&result).map_err(Self::fix_domain_error)?;
-
-        let hash = Self::compute_hash(&result);
-        let length = u32::try_from(new_len).map_err(|_| KeyParseError::TooLong {
-            max_length: u32::MAX as usize,
-            actual_length: new_len,
-        })?;
+    #[inline]
+    #[must_use]
+    pub fn reveal(&self) -> &str {
+        &self.inner
+    }
 
-        Ok(Self {
-            inner: result,
-            hash,
-            length,
-            _marker: PhantomData,
-        })
+    /// Returns the domain name for this key type
+    ///
+    /// This is a compile-time constant that identifies which domain
+    /// this key belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct UserDomain;
+    /// impl KeyDomain for UserDomain {
+    ///     const DOMAIN_NAME: &'static str = "user";
+    /// }
+    /// type UserKey = Key<UserDomain>;
+    ///
+    /// let key = UserKey::new("john")?;
+    /// assert_eq!(key.domain(), "user");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::inline_always, clippy::unused_self)]
+    #[must_use]
+    pub const fn domain(&self) -> &'static str {
+        T::DOMAIN_NAME
     }
 
-    /// Returns the key with a suffix if it doesn't already have it
+    /// Returns the schema version of the domain's rules
     ///
-    /// This method efficiently adds a suffix to a key if it doesn't already
-    /// end with that suffix.
+    /// Persist this alongside stored keys to detect keys created under an
+    /// older version of the domain's normalization rules.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `suffix` - The suffix to ensure is present
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct UserDomain;
+    /// impl KeyDomain for UserDomain {
+    ///     const DOMAIN_NAME: &'static str = "user";
+    ///     const SCHEMA_VERSION: u32 = 2;
+    /// }
+    /// type UserKey = Key<UserDomain>;
+    ///
+    /// let key = UserKey::new("john")?;
+    /// assert_eq!(key.schema_version(), 2);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::inline_always, clippy::unused_self)]
+    #[must_use]
+    pub const fn schema_version(&self) -> u32 {
+        T::SCHEMA_VERSION
+    }
+
+    /// Returns the length of the key string in bytes
+    ///
+    /// This is an O(1) operation using a cached length value. For keys with
+    /// non-ASCII content the byte length exceeds the number of characters;
+    /// use [`Key::char_count`] for the latter.
     ///
     /// # Examples
     ///
@@ -999,64 +1433,23 @@ impl<T: KeyDomain> Key<T> {
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("user")?;
-    /// let suffixed = key.ensure_suffix("_profile")?;
-    /// assert_eq!(suffixed.as_str(), "user_profile");
-    ///
-    /// // If suffix already exists, returns the same key
-    /// let already_suffixed = suffixed.ensure_suffix("_profile")?;
-    /// assert_eq!(already_suffixed.as_str(), "user_profile");
+    /// let key = TestKey::new("example")?;
+    /// assert_eq!(key.len(), 7);
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
-    /// # Errors
-    ///
-    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
-    pub fn ensure_suffix(&self, suffix: &str) -> Result<Self, KeyParseError> {
-        if self.ends_with(suffix) {
-            return Ok(self.clone());
-        }
-
-        let new_len = self.len() + suffix.len();
-        if new_len > T::MAX_LENGTH {
-            return Err(KeyParseError::TooLong {
-                max_length: T::MAX_LENGTH,
-                actual_length: new_len,
-            });
-        }
-
-        let result = utils::add_suffix_optimized(&self.inner, suffix, T::MAX_LENGTH);
-
-        // Quick validation of suffix only
-        for (i, c) in suffix.chars().enumerate() {
-            if !T::allowed_characters(c) {
-                return Err(KeyParseError::InvalidCharacter {
-                    character: c,
-                    position: self.len() + i,
-                    expected: Some("allowed by domain"),
-                });
-            }
-        }
-
-        T::validate_domain_rules(&result).map_err(Self::fix_domain_error)?;
-
-        let hash = Self::compute_hash(&result);
-        let length = new_len.try_into().map_err(|_| KeyParseError::TooLong {
-            max_length: u32::MAX as usize,
-            actual_length: new_len,
-        })?;
-
-        Ok(Self {
-            inner: result,
-            hash,
-            length,
-            _marker: PhantomData,
-        })
+    #[inline(always)]
+    #[allow(clippy::inline_always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.length as usize
     }
 
-    /// Get validation rules that this key satisfies
+    /// Returns the number of Unicode scalar values (`char`s) in the key
     ///
-    /// Returns detailed information about the validation characteristics
-    /// of this key and its domain, useful for debugging and introspection.
+    /// [`Key::len`] is the byte length. For domains with
+    /// [`KeyDomain::ASCII_ONLY`] set the two are equal and this is O(1);
+    /// otherwise the key is scanned, which is O(n). The count is not cached
+    /// so that keys stay the same size.
     ///
     /// # Examples
     ///
@@ -1067,535 +1460,5185 @@ impl<T: KeyDomain> Key<T> {
     /// struct TestDomain;
     /// impl KeyDomain for TestDomain {
     ///     const DOMAIN_NAME: &'static str = "test";
-    ///     const MAX_LENGTH: usize = 32;
-    ///     const HAS_CUSTOM_VALIDATION: bool = true;
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         c.is_alphanumeric()
+    ///     }
     /// }
     /// type TestKey = Key<TestDomain>;
     ///
-    /// let key = TestKey::new("example")?;
-    /// let info = key.validation_info();
-    ///
-    /// assert_eq!(info.domain, "test");
-    /// assert_eq!(info.max_length, 32);
-    /// assert_eq!(info.length, 7);
-    /// assert!(info.has_custom_validation);
+    /// let key = TestKey::new("héllo")?;
+    /// assert_eq!(key.len(), 6);
+    /// assert_eq!(key.char_count(), 5);
     /// # Ok::<(), domain_key::KeyParseError>(())
     /// ```
+    #[inline]
     #[must_use]
-    pub fn validation_info(&self) -> KeyValidationInfo {
-        KeyValidationInfo {
-            domain: T::DOMAIN_NAME,
-            max_length: T::MAX_LENGTH,
-            length: self.len(),
-            has_custom_validation: T::HAS_CUSTOM_VALIDATION,
-            has_custom_normalization: T::HAS_CUSTOM_NORMALIZATION,
+    pub fn char_count(&self) -> usize {
+        if T::ASCII_ONLY {
+            self.len()
+        } else {
+            self.inner.chars().count()
         }
     }
-}
-
-// ============================================================================
-// KEY IMPLEMENTATION - HELPER METHODS
-// ============================================================================
 
-impl<T: KeyDomain> Key<T> {
-    /// Fix domain name in domain validation errors
+    /// Returns the length of the key in UTF-16 code units
     ///
-    /// This helper ensures that domain validation errors have the correct
-    /// domain name, even when they're created generically.
+    /// This is the length a JavaScript host reports for the same string.
+    /// For domains with [`KeyDomain::ASCII_ONLY`] set it is the cached byte
+    /// length; otherwise the key is re-encoded, which is O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         c.is_alphanumeric() || c == '_'
+    ///     }
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("café")?;
+    /// assert_eq!(key.len(), 5);
+    /// assert_eq!(key.len_utf16(), 4);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
     #[inline]
-    fn fix_domain_error(e: KeyParseError) -> KeyParseError {
-        match e {
-            KeyParseError::DomainValidation { message, .. } => KeyParseError::DomainValidation {
-                domain: T::DOMAIN_NAME,
-                message,
-            },
-            other => other,
+    #[must_use]
+    pub fn len_utf16(&self) -> usize {
+        if T::ASCII_ONLY {
+            self.len()
+        } else {
+            self.inner.encode_utf16().count()
         }
     }
 
-    /// Common validation pipeline
+    /// Returns true if the key is empty (this should never happen for valid keys)
     ///
-    /// Performs validation that's common to all domains: length checking,
-    /// character validation, and structural validation.
+    /// Since empty keys are rejected during validation, this method should
+    /// always return `false` for properly constructed keys. It's provided
+    /// for completeness and debugging purposes.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
-    pub(crate) fn validate_common<D: KeyDomain>(key: &str) -> Result<(), KeyParseError> {
-        let trimmed = key.trim();
-
-        if trimmed.is_empty() {
-            return Err(KeyParseError::Empty);
-        }
-
-        if trimmed.len() > D::MAX_LENGTH {
-            return Err(KeyParseError::TooLong {
-                max_length: D::MAX_LENGTH,
-                actual_length: trimmed.len(),
-            });
-        }
-
-        if trimmed.len() < D::min_length() {
-            return Err(KeyParseError::TooLong {
-                max_length: D::min_length(),
-                actual_length: trimmed.len(),
-            });
-        }
-
-        // Use fast validation
-        Self::validate_fast::<D>(trimmed)
-    }
-
-    /// Fast validation path using optimized algorithms
-    /// # Errors
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
     ///
-    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
-    fn validate_fast<D: KeyDomain>(key: &str) -> Result<(), KeyParseError> {
-        let mut chars = key.char_indices();
-        let mut prev_char = None;
-
-        // Validate first character
-        if let Some((pos, first)) = chars.next() {
-            let char_allowed = is_ascii_allowed_fast(first) || D::allowed_start_character(first);
-
-            if !char_allowed {
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("example")?;
+    /// assert!(!key.is_empty());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::inline_always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the key's bytes as a fixed-size array
+    ///
+    /// Useful for fixed-width keys that should be stored inline in compact
+    /// data structures. Returns `None` unless the key is exactly `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("abcd")?;
+    /// assert_eq!(key.try_as_array::<4>(), Some(*b"abcd"));
+    /// assert_eq!(key.try_as_array::<8>(), None);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_as_array<const N: usize>(&self) -> Option<[u8; N]> {
+        self.inner.as_bytes().try_into().ok()
+    }
+
+    /// Returns the cached hash value
+    ///
+    /// This hash is computed once during key creation and cached for the
+    /// lifetime of the key. It's used internally for hash-based collections
+    /// and can be useful for custom hash-based data structures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key1 = TestKey::new("example")?;
+    /// let key2 = TestKey::new("example")?;
+    /// let key3 = TestKey::new("different")?;
+    ///
+    /// // Same keys have same hash
+    /// assert_eq!(key1.hash(), key2.hash());
+    /// // Different keys have different hashes (with high probability)
+    /// assert_ne!(key1.hash(), key3.hash());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::inline_always)]
+    #[must_use]
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns a hash of the key that never depends on build configuration
+    ///
+    /// [`Key::hash`] uses the feature-selected algorithm, which changes with
+    /// feature flags, target CPU and (with `secure`) the process seed. This
+    /// method always computes 64-bit FNV-1a over the normalized key bytes,
+    /// so the value is identical across features, architectures and crate
+    /// versions. Use it for anything persisted or shared between nodes.
+    /// It is computed on each call and does not resist hash flooding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("hello")?;
+    /// assert_eq!(key.stable_hash(), 0xa430_d846_80aa_bd0b);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn stable_hash(&self) -> u64 {
+        Self::fnv1a_hash(self.as_bytes())
+    }
+
+    /// Returns the cached hash as 16 zero-padded lowercase hex digits
+    ///
+    /// This is the standard format for printing key hashes, e.g. for log
+    /// correlation. Use [`Key::hash_hex_into`] to write it without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("example")?;
+    /// let hex = key.hash_hex();
+    /// assert_eq!(hex.len(), 16);
+    /// assert_eq!(hex, format!("{:016x}", key.hash()));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn hash_hex(&self) -> String {
+        let mut out = String::with_capacity(16);
+        // Writing to a String cannot fail
+        let _ = self.hash_hex_into(&mut out);
+        out
+    }
+
+    /// Writes the cached hash as 16 zero-padded lowercase hex digits
+    ///
+    /// Same format as [`Key::hash_hex`], written into any [`fmt::Write`]
+    /// sink without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    /// use std::fmt::Write;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("example")?;
+    /// let mut line = String::from("hash=");
+    /// key.hash_hex_into(&mut line).unwrap();
+    /// assert_eq!(line.len(), 5 + 16);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    pub fn hash_hex_into(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        write!(out, "{:016x}", self.hash)
+    }
+
+    /// Checks if this key starts with the given prefix
+    ///
+    /// This is a simple string prefix check that can be useful for
+    /// categorizing or filtering keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix string to check for
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile")?;
+    /// assert!(key.starts_with("user_"));
+    /// assert!(!key.starts_with("admin_"));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.inner.starts_with(prefix)
+    }
+
+    /// Checks if this key ends with the given suffix
+    ///
+    /// This is a simple string suffix check that can be useful for
+    /// categorizing or filtering keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The suffix string to check for
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile")?;
+    /// assert!(key.ends_with("_profile"));
+    /// assert!(!key.ends_with("_settings"));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.inner.ends_with(suffix)
+    }
+
+    /// Returns the index of the first prefix that the key starts with
+    ///
+    /// Prefixes are checked in order and the search stops at the first match,
+    /// which makes this a convenient dispatch primitive for routing. The cost
+    /// grows linearly with the number of prefixes; for large prefix sets a
+    /// trie built once and reused is the better choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefixes` - Candidate prefixes, in priority order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("api_v2_users")?;
+    /// assert_eq!(key.matches_any_prefix(&["web_", "api_v1_", "api_"]), Some(2));
+    /// assert_eq!(key.matches_any_prefix(&["admin_"]), None);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn matches_any_prefix(&self, prefixes: &[&str]) -> Option<usize> {
+        prefixes
+            .iter()
+            .position(|prefix| self.inner.starts_with(prefix))
+    }
+
+    /// Checks if this key contains the given substring
+    ///
+    /// This performs a substring search within the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The substring to search for
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile_settings")?;
+    /// assert!(key.contains("profile"));
+    /// assert!(!key.contains("admin"));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.inner.contains(pattern)
+    }
+
+    /// Converts this key into a looser parent domain without revalidation
+    ///
+    /// The conversion is infallible and reuses the stored string and cached
+    /// hash. It is only available when the domain declares
+    /// [`SubdomainOf<P>`](SubdomainOf); the parent's `post_validate`
+    /// and `on_create` hooks are not run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{DefaultDomain, Key, KeyDomain, SubdomainOf};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct SlugDomain;
+    /// impl KeyDomain for SlugDomain {
+    ///     const DOMAIN_NAME: &'static str = "slug";
+    ///     const MAX_LENGTH: usize = 32;
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         c.is_ascii_lowercase() || c == '_'
+    ///     }
+    /// }
+    /// impl SubdomainOf<DefaultDomain> for SlugDomain {}
+    ///
+    /// let slug = Key::<SlugDomain>::new("draft")?;
+    /// let general: Key<DefaultDomain> = slug.widen();
+    /// assert_eq!(general.as_str(), "draft");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn widen<P: KeyDomain>(self) -> Key<P>
+    where
+        T: SubdomainOf<P>,
+    {
+        Key {
+            parts: PartPositions::record::<P>(&self.inner),
+            inner: self.inner,
+            hash: self.hash,
+            length: self.length,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Converts this key into another domain, revalidating its content
+    ///
+    /// The key's string is run through the full creation pipeline of `U`,
+    /// so the result is normalized and checked exactly as
+    /// `Key::<U>::new(self.as_str())` would be.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the content is not valid in domain `U`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{DefaultDomain, Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct ShortDomain;
+    /// impl KeyDomain for ShortDomain {
+    ///     const DOMAIN_NAME: &'static str = "short";
+    ///     const MAX_LENGTH: usize = 8;
+    /// }
+    ///
+    /// let key = Key::<DefaultDomain>::new("tiny")?;
+    /// assert!(key.try_into_domain::<ShortDomain>().is_ok());
+    ///
+    /// let long = Key::<DefaultDomain>::new("much_too_long")?;
+    /// assert!(long.try_into_domain::<ShortDomain>().is_err());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn try_into_domain<U: KeyDomain>(&self) -> Result<Key<U>, KeyParseError> {
+        Key::<U>::new(self.as_str())
+    }
+
+    /// Moves this key into another domain, revalidating its content
+    ///
+    /// The consuming counterpart of [`try_into_domain`](Self::try_into_domain).
+    /// The stored string is handed to `B`'s creation pipeline, reusing the
+    /// allocation where possible; hash and length are recomputed because `B`
+    /// may normalize differently.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the content is not valid in domain `B`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{DefaultDomain, IdentifierDomain, Key};
+    ///
+    /// let key = Key::<DefaultDomain>::new("user_id")?;
+    /// let ident: Key<IdentifierDomain> = key.convert_to()?;
+    /// assert_eq!(ident.as_str(), "user_id");
+    ///
+    /// let dotted = Key::<DefaultDomain>::new("user.id")?;
+    /// assert!(dotted.convert_to::<IdentifierDomain>().is_err());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn convert_to<B: KeyDomain>(self) -> Result<Key<B>, KeyParseError> {
+        Key::<B>::from_string(self.into_string())
+    }
+
+    /// Compares the content of this key with a key from any domain
+    ///
+    /// Unlike `==`, which only compares keys of the same domain, this method
+    /// compares the normalized string content and ignores the domain entirely.
+    /// It is intended for reconciliation between compatible domains, such as
+    /// a generic `DefaultDomain` mirror and a typed domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Key from any domain to compare against
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{DefaultDomain, Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct UserDomain;
+    /// impl KeyDomain for UserDomain {
+    ///     const DOMAIN_NAME: &'static str = "user";
+    /// }
+    ///
+    /// let typed = Key::<UserDomain>::new("john_doe")?;
+    /// let mirror = Key::<DefaultDomain>::new("john_doe")?;
+    ///
+    /// assert!(typed.content_eq(&mirror));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn content_eq<U: KeyDomain>(&self, other: &Key<U>) -> bool {
+        self.as_str() == other.as_str()
+    }
+
+    /// Returns an iterator over the characters of the key
+    ///
+    /// This provides access to individual characters in the key string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("abc")?;
+    /// let chars: Vec<char> = key.chars().collect();
+    /// assert_eq!(chars, vec!['a', 'b', 'c']);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn chars(&self) -> core::str::Chars<'_> {
+        self.inner.chars()
+    }
+
+    /// Returns the first character of the key
+    ///
+    /// Keys are never empty, so there is always a first character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("hello")?;
+    /// assert_eq!(key.first_char(), 'h');
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn first_char(&self) -> char {
+        // Validation rejects empty keys; the fallback is never observed
+        self.inner.chars().next().unwrap_or('\0')
+    }
+
+    /// Returns the last character of the key
+    ///
+    /// Keys are never empty, so there is always a last character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("hello")?;
+    /// assert_eq!(key.last_char(), 'o');
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn last_char(&self) -> char {
+        // Validation rejects empty keys; the fallback is never observed
+        self.inner.chars().next_back().unwrap_or('\0')
+    }
+
+    /// Splits the key by a delimiter and returns an iterator
+    ///
+    /// This method provides consistent split functionality.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Character to split on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile_settings")?;
+    /// let parts: Vec<&str> = key.split('_').collect();
+    /// assert_eq!(parts, vec!["user", "profile", "settings"]);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn split(&self, delimiter: char) -> SplitIterator<'_> {
+        SplitIterator::Cached(utils::new_split_cache(&self.inner, delimiter))
+    }
+
+    /// Split operation for consistent API
+    ///
+    /// This method provides the same functionality as `split()` but with explicit naming
+    /// for cases where caching behavior needs to be clear.
+    #[must_use]
+    pub fn split_cached(&self, delimiter: char) -> SplitCache<'_> {
+        utils::new_split_cache(&self.inner, delimiter)
+    }
+
+    /// Returns the `n`th part of the key split by `delimiter`
+    ///
+    /// Keys of domains with [`KeyDomain::FREQUENTLY_SPLIT`] set record the
+    /// positions of the domain's default separator when they are created, so
+    /// queries on that separator are O(1). Other domains and delimiters scan
+    /// only as far as the requested part.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Character to split on
+    /// * `n` - The part index (0-based)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, PathDomain};
+    ///
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// let key = PathKey::new("tenant/users/42")?;
+    /// assert_eq!(key.nth_part('/', 1), Some("users"));
+    /// assert_eq!(key.nth_part('/', 3), None);
+    /// assert_eq!(key.part_count('/'), 3);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn nth_part(&self, delimiter: char, n: usize) -> Option<&str> {
+        if let Some(positions) = self.parts.get::<T>(delimiter) {
+            let start = match n.checked_sub(1) {
+                None => 0,
+                Some(i) => *positions.get(i)? as usize + delimiter.len_utf8(),
+            };
+            let end = positions
+                .get(n)
+                .map_or(self.inner.len(), |&pos| pos as usize);
+            return Some(&self.inner[start..end]);
+        }
+        self.inner.split(delimiter).nth(n)
+    }
+
+    /// Returns the number of parts the key splits into at `delimiter`
+    ///
+    /// A key without the delimiter has one part. Uses the same cached
+    /// positions as [`Key::nth_part`].
+    #[inline]
+    #[must_use]
+    pub fn part_count(&self, delimiter: char) -> usize {
+        if let Some(positions) = self.parts.get::<T>(delimiter) {
+            return positions.len() + 1;
+        }
+        utils::count_char(&self.inner, delimiter) + 1
+    }
+
+    /// Records the delimiter positions of this key in a standalone cache
+    ///
+    /// Keys of `FREQUENTLY_SPLIT` domains already cache the positions of
+    /// their default separator. Use this for other delimiters or domains
+    /// when many part queries run against the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, PathDomain};
+    ///
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// let key = PathKey::new("tenant/users/42")?;
+    /// let cache = key.part_positions('/');
+    /// assert_eq!(cache.part_count(), 3);
+    /// assert_eq!(cache.get_part(2), Some("42"));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn part_positions(&self, delimiter: char) -> utils::PositionCache {
+        utils::PositionCache::new(&self.inner, delimiter)
+    }
+
+    /// Splits the key by a string delimiter and returns an iterator
+    ///
+    /// This method splits the key using a string pattern rather than a single character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user-and-profile-and-settings")?;
+    /// let parts: Vec<&str> = key.split_str("-and-").collect();
+    /// assert_eq!(parts, vec!["user", "profile", "settings"]);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn split_str<'a>(&'a self, delimiter: &'a str) -> core::str::Split<'a, &'a str> {
+        self.inner.split(delimiter)
+    }
+
+    /// Splits the key at the first occurrence of `delimiter`
+    ///
+    /// Delegates to [`str::split_once`] and returns `None` when the
+    /// delimiter does not occur. No allocation takes place.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Character to split on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         c.is_ascii_alphanumeric() || c == '_' || c == '@'
+    ///     }
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user@tenant")?;
+    /// assert_eq!(key.split_once('@'), Some(("user", "tenant")));
+    /// assert_eq!(key.split_once('_'), None);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_once(&self, delimiter: char) -> Option<(&str, &str)> {
+        self.inner.split_once(delimiter)
+    }
+
+    /// Splits the key at the last occurrence of `delimiter`
+    ///
+    /// Delegates to [`str::rsplit_once`] and returns `None` when the
+    /// delimiter does not occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Character to split on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_123_profile")?;
+    /// assert_eq!(key.rsplit_once('_'), Some(("user_123", "profile")));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rsplit_once(&self, delimiter: char) -> Option<(&str, &str)> {
+        self.inner.rsplit_once(delimiter)
+    }
+
+    /// Splits the key by a delimiter, keeping the delimiter on each segment
+    ///
+    /// Every segment except possibly the last ends with `delimiter`, so
+    /// concatenating any prefix of the segments yields a prefix of the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Character to split on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile_settings")?;
+    /// let parts: Vec<&str> = key.split_inclusive('_').collect();
+    /// assert_eq!(parts, vec!["user_", "profile_", "settings"]);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn split_inclusive(&self, delimiter: char) -> core::str::SplitInclusive<'_, char> {
+        self.inner.split_inclusive(delimiter)
+    }
+
+    /// Splits the key on the domain's default separator into owned segments
+    ///
+    /// Unlike [`Key::split`], the returned segments do not borrow from the
+    /// key and can outlive it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let segments = {
+    ///     let key = TestKey::new("user_profile_settings")?;
+    ///     key.to_owned_segments()
+    /// };
+    /// assert_eq!(segments, vec!["user", "profile", "settings"]);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn to_owned_segments(&self) -> Vec<String> {
+        self.inner
+            .split(T::default_separator())
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Returns the number of levels in a hierarchical key
+    ///
+    /// Levels are delimited by the domain's default separator only; other
+    /// separator-like characters are not counted. A key without the
+    /// separator has depth 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, PathDomain};
+    ///
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// assert_eq!(PathKey::new("tenant")?.depth(), 1);
+    /// assert_eq!(PathKey::new("tenant/users/42")?.depth(), 3);
+    /// assert_eq!(PathKey::new("tenant/users_42")?.depth(), 2);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        utils::count_char(&self.inner, T::default_separator()) + 1
+    }
+
+    /// Returns the parent of a hierarchical key
+    ///
+    /// Drops the last segment after the domain's default separator and
+    /// revalidates the rest. Returns `None` when the key has no separator or
+    /// when the remaining prefix is not a valid key on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, PathDomain};
+    ///
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// let key = PathKey::new("users/profile/settings")?;
+    /// assert_eq!(key.parent().unwrap().as_str(), "users/profile");
+    /// assert!(PathKey::new("users")?.parent().is_none());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        let (parent, _) = self.inner.rsplit_once(T::default_separator())?;
+        Self::new(parent).ok()
+    }
+
+    /// Returns an iterator over the ancestors of a hierarchical key
+    ///
+    /// Ancestors are the prefixes that end right before an occurrence of the
+    /// domain's default separator, yielded from shallowest to deepest. The key
+    /// itself is not included. Each ancestor goes through full validation, and
+    /// prefixes that are not valid keys on their own are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, PathDomain};
+    ///
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// let key = PathKey::new("a/b/c/d")?;
+    /// let ancestors: Vec<String> = key.ancestors().map(|k| k.as_str().to_string()).collect();
+    /// assert_eq!(ancestors, vec!["a", "a/b", "a/b/c"]);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn ancestors(&self) -> impl Iterator<Item = Self> + '_ {
+        self.inner
+            .match_indices(T::default_separator())
+            .filter_map(move |(pos, _)| Self::new(&self.inner[..pos]).ok())
+    }
+
+    /// Returns the longest common leading substring of two keys
+    ///
+    /// Unlike [`Key::common_ancestor`], this compares characters rather than
+    /// segments, so the result may end in the middle of a segment. The
+    /// prefix always ends on a UTF-8 character boundary and may be empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Key of the same domain to compare with
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let a = TestKey::new("user_profile")?;
+    /// let b = TestKey::new("user_preferences")?;
+    /// assert_eq!(a.common_prefix(&b), "user_pr");
+    /// assert_eq!(a.common_prefix_len(&b), 7);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn common_prefix<'a>(&'a self, other: &'a Self) -> &'a str {
+        &self.inner[..self.common_prefix_len(other)]
+    }
+
+    /// Returns the byte length of [`Key::common_prefix`]
+    #[must_use]
+    pub fn common_prefix_len(&self, other: &Self) -> usize {
+        self.inner
+            .char_indices()
+            .zip(other.inner.chars())
+            .find(|&((_, a), b)| a != b)
+            .map_or_else(|| self.len().min(other.len()), |((index, _), _)| index)
+    }
+
+    /// Returns the deepest key shared by both keys' leading segments
+    ///
+    /// Segments are compared whole using the domain's default separator, so
+    /// `a/bc` and `a/bd` share `a`, not `a/b`. If one key is a segment prefix
+    /// of the other, the shorter key is returned. The result is revalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The key to compare with
+    ///
+    /// # Returns
+    ///
+    /// * `Some(key)` with the shared leading segments
+    /// * `None` if the first segments differ or the shared prefix is not a valid key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, PathDomain};
+    ///
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// let left = PathKey::new("a/b/c")?;
+    /// let right = PathKey::new("a/b/d")?;
+    /// assert_eq!(left.common_ancestor(&right), Some(PathKey::new("a/b")?));
+    ///
+    /// let left = PathKey::new("a/bc")?;
+    /// let right = PathKey::new("a/bd")?;
+    /// assert_eq!(left.common_ancestor(&right), Some(PathKey::new("a")?));
+    ///
+    /// assert!(left.common_ancestor(&PathKey::new("x/bc")?).is_none());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn common_ancestor(&self, other: &Self) -> Option<Self> {
+        let separator = T::default_separator();
+        let mut shared_end = None;
+
+        let mut ours = self.inner.split(separator);
+        let mut theirs = other.inner.split(separator);
+        let mut end = 0;
+        while let (Some(a), Some(b)) = (ours.next(), theirs.next()) {
+            if a != b {
+                break;
+            }
+            end += a.len();
+            shared_end = Some(end);
+            end += separator.len_utf8();
+        }
+
+        Self::new(&self.inner[..shared_end?]).ok()
+    }
+
+    /// Splits the key into two keys at the nth segment boundary
+    ///
+    /// Segments are delimited by the domain's default separator. The first
+    /// returned key contains the first `n` segments and the second contains
+    /// the remainder. Both halves are re-validated independently, so a split
+    /// that would produce an invalid key for the domain yields `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of segments to keep in the head key
+    ///
+    /// # Returns
+    ///
+    /// * `Some((head, tail))` if both halves are valid keys
+    /// * `None` if `n` is zero, not smaller than the segment count, or either half is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("tenant_user_profile")?;
+    /// let (head, tail) = key.split_at_segment(1).unwrap();
+    /// assert_eq!(head.as_str(), "tenant");
+    /// assert_eq!(tail.as_str(), "user_profile");
+    ///
+    /// assert!(key.split_at_segment(0).is_none());
+    /// assert!(key.split_at_segment(3).is_none());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn split_at_segment(&self, n: usize) -> Option<(Self, Self)> {
+        if n == 0 {
+            return None;
+        }
+
+        let separator = T::default_separator();
+        let pos = utils::find_nth_char(&self.inner, separator, n - 1)?;
+        let head = &self.inner[..pos];
+        let tail = &self.inner[pos + separator.len_utf8()..];
+
+        let head = Self::new(head).ok()?;
+        let tail = Self::new(tail).ok()?;
+        Some((head, tail))
+    }
+
+    /// Transforms each segment of the key and rebuilds a validated key
+    ///
+    /// The key is split on the domain's default separator, `f` is applied
+    /// to every segment, and the results are joined with the same separator.
+    /// The rebuilt key goes through full validation and normalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Transformation applied to each segment in order
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::InvalidStructure` if any transformed segment
+    /// is empty, or any validation error for the rebuilt key (for example
+    /// `InvalidCharacter` if a segment contains a character the domain rejects)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_john_profile")?;
+    /// let masked = key.map_segments(|segment| format!("{}x", &segment[..1]))?;
+    /// assert_eq!(masked.as_str(), "ux_jx_px");
+    ///
+    /// assert!(key.map_segments(|_| String::new()).is_err());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn map_segments(&self, mut f: impl FnMut(&str) -> String) -> Result<Self, KeyParseError> {
+        let separator = T::default_separator();
+        let mut rebuilt = String::with_capacity(self.len());
+
+        for (i, segment) in self.inner.split(separator).enumerate() {
+            let mapped = f(segment);
+            if mapped.is_empty() {
+                return Err(KeyParseError::InvalidStructure {
+                    reason: "segment transform produced an empty segment",
+                });
+            }
+            if i > 0 {
+                rebuilt.push(separator);
+            }
+            rebuilt.push_str(&mapped);
+        }
+
+        if let Some(same) = self.with_hash_cached_from(&rebuilt) {
+            return Ok(same);
+        }
+        Self::from_string(rebuilt)
+    }
+
+    /// Returns an ASCII-uppercased copy of the key, revalidated
+    ///
+    /// The transformed string goes through the full creation pipeline, so
+    /// the result has a freshly computed hash and length and is rejected if
+    /// uppercasing violates a domain rule. On case-insensitive domains
+    /// normalization lowercases the result again, so this is a no-op there.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the uppercased key is invalid for the domain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{IdentifierDomain, Key};
+    ///
+    /// let ident = Key::<IdentifierDomain>::new("max_retries")?;
+    /// let constant = ident.to_ascii_uppercase()?;
+    /// assert_eq!(constant.as_str(), "MAX_RETRIES");
+    /// assert_eq!(constant.to_ascii_lowercase()?, ident);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> Result<Self, KeyParseError> {
+        let upper = self.inner.to_ascii_uppercase();
+        if let Some(same) = self.with_hash_cached_from(&upper) {
+            return Ok(same);
+        }
+        Self::from_string(upper)
+    }
+
+    /// Returns an ASCII-lowercased copy of the key, revalidated
+    ///
+    /// The counterpart of [`Key::to_ascii_uppercase`], with the same
+    /// revalidation guarantees.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the lowercased key is invalid for the domain
+    pub fn to_ascii_lowercase(&self) -> Result<Self, KeyParseError> {
+        let lower = self.inner.to_ascii_lowercase();
+        if let Some(same) = self.with_hash_cached_from(&lower) {
+            return Ok(same);
+        }
+        Self::from_string(lower)
+    }
+
+    /// Converts the key to another naming convention and revalidates it
+    ///
+    /// Words are detected at `_`, `-` and `.` separators and at lowercase to
+    /// uppercase transitions, then reassembled in the requested style. The
+    /// result goes through full validation, so converting to a style whose
+    /// separator the domain forbids returns an error.
+    ///
+    /// Note that case-insensitive domains lowercase keys during
+    /// normalization, so `Camel` and `Pascal` are only distinguishable from
+    /// plain concatenation on case-sensitive domains.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The target naming convention
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the converted key is invalid for the domain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{CaseStyle, Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct FieldDomain;
+    /// impl KeyDomain for FieldDomain {
+    ///     const DOMAIN_NAME: &'static str = "field";
+    ///     const CASE_INSENSITIVE: bool = false;
+    /// }
+    /// type FieldKey = Key<FieldDomain>;
+    ///
+    /// let column = FieldKey::new("created_at_utc")?;
+    /// assert_eq!(column.to_case(CaseStyle::Camel)?.as_str(), "createdAtUtc");
+    /// assert_eq!(column.to_case(CaseStyle::Pascal)?.as_str(), "CreatedAtUtc");
+    /// assert_eq!(column.to_case(CaseStyle::Kebab)?.as_str(), "created-at-utc");
+    ///
+    /// let field = FieldKey::new("createdAtUtc")?;
+    /// assert_eq!(field.to_case(CaseStyle::Snake)?.as_str(), "created_at_utc");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn to_case(&self, style: CaseStyle) -> Result<Self, KeyParseError> {
+        let mut converted = String::with_capacity(self.len() + 4);
+        for (word_index, word) in case_words(&self.inner).into_iter().enumerate() {
+            match style {
+                CaseStyle::Snake | CaseStyle::Kebab => {
+                    if word_index > 0 {
+                        converted.push(if style == CaseStyle::Snake { '_' } else { '-' });
+                    }
+                    converted.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+                }
+                CaseStyle::Camel | CaseStyle::Pascal => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        if word_index == 0 && style == CaseStyle::Camel {
+                            converted.push(first.to_ascii_lowercase());
+                        } else {
+                            converted.push(first.to_ascii_uppercase());
+                        }
+                    }
+                    converted.extend(chars.map(|c| c.to_ascii_lowercase()));
+                }
+            }
+        }
+
+        if let Some(same) = self.with_hash_cached_from(&converted) {
+            return Ok(same);
+        }
+        Self::new(converted)
+    }
+
+    /// Returns the key with a prefix if it doesn't already have it
+    ///
+    /// This method efficiently adds a prefix to a key if it doesn't already
+    /// start with that prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix to ensure is present
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("profile")?;
+    /// let prefixed = key.ensure_prefix("user_")?;
+    /// assert_eq!(prefixed.as_str(), "user_profile");
+    ///
+    /// // If prefix already exists, returns the same key
+    /// let already_prefixed = prefixed.ensure_prefix("user_")?;
+    /// assert_eq!(already_prefixed.as_str(), "user_profile");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
+    pub fn ensure_prefix(&self, prefix: &str) -> Result<Self, KeyParseError> {
+        if self.starts_with(prefix) {
+            return Ok(self.clone());
+        }
+
+        let new_len = prefix.len() + self.len();
+        if new_len > T::MAX_LENGTH {
+            return Err(KeyParseError::TooLong {
+                max_length: T::MAX_LENGTH,
+                actual_length: new_len,
+            });
+        }
+
+        let result = utils::add_prefix_optimized(&self.inner, prefix, T::MAX_LENGTH);
+
+        // Quick validation of prefix only
+        for (i, c) in prefix.chars().enumerate() {
+            if !T::allowed_characters(c) {
+                return Err(KeyParseError::InvalidCharacter {
+                    character: c,
+                    position: i,
+                    expected: Some("allowed by domain"),
+                });
+            }
+        }
+
+        let inner = match Self::check_domain_rules(&result)? {
+            Cow::Owned(canonical) => Storage::from(canonical),
+            Cow::Borrowed(_) => into_storage(result),
+        };
+        Self::finalize(inner)
+    }
+
+    /// Returns the key with a suffix if it doesn't already have it
+    ///
+    /// This method efficiently adds a suffix to a key if it doesn't already
+    /// end with that suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The suffix to ensure is present
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user")?;
+    /// let suffixed = key.ensure_suffix("_profile")?;
+    /// assert_eq!(suffixed.as_str(), "user_profile");
+    ///
+    /// // If suffix already exists, returns the same key
+    /// let already_suffixed = suffixed.ensure_suffix("_profile")?;
+    /// assert_eq!(already_suffixed.as_str(), "user_profile");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
+    pub fn ensure_suffix(&self, suffix: &str) -> Result<Self, KeyParseError> {
+        if self.ends_with(suffix) {
+            return Ok(self.clone());
+        }
+
+        let new_len = self.len() + suffix.len();
+        if new_len > T::MAX_LENGTH {
+            return Err(KeyParseError::TooLong {
+                max_length: T::MAX_LENGTH,
+                actual_length: new_len,
+            });
+        }
+
+        let result = utils::add_suffix_optimized(&self.inner, suffix, T::MAX_LENGTH);
+
+        // Quick validation of suffix only
+        for (i, c) in suffix.chars().enumerate() {
+            if !T::allowed_characters(c) {
+                return Err(KeyParseError::InvalidCharacter {
+                    character: c,
+                    position: self.len() + i,
+                    expected: Some("allowed by domain"),
+                });
+            }
+        }
+
+        let inner = match Self::check_domain_rules(&result)? {
+            Cow::Owned(canonical) => Storage::from(canonical),
+            Cow::Borrowed(_) => into_storage(result),
+        };
+        Self::finalize(inner)
+    }
+
+    /// Returns the key without `prefix`, revalidated, if it has that prefix
+    ///
+    /// Returns `Ok(None)` when the key does not start with `prefix`. The
+    /// remainder goes through full validation, so stripping can fail: an
+    /// empty remainder yields `Err(Empty)`, and a remainder that starts with
+    /// a disallowed character is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix to remove
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the remainder is not a valid key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile")?;
+    /// assert_eq!(key.strip_prefix("user_")?.unwrap().as_str(), "profile");
+    /// assert!(key.strip_prefix("admin_")?.is_none());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Option<Self>, KeyParseError> {
+        self.inner.strip_prefix(prefix).map(Self::new).transpose()
+    }
+
+    /// Returns the key without `suffix`, revalidated, if it has that suffix
+    ///
+    /// The counterpart of [`Key::strip_prefix`]: returns `Ok(None)` when the
+    /// suffix is absent and revalidates the remainder otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The suffix to remove
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the remainder is not a valid key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_profile")?;
+    /// assert_eq!(key.strip_suffix("_profile")?.unwrap().as_str(), "user");
+    /// assert!(key.strip_suffix("_settings")?.is_none());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Option<Self>, KeyParseError> {
+        self.inner.strip_suffix(suffix).map(Self::new).transpose()
+    }
+
+    /// Returns a key with every occurrence of `from` replaced by `to`
+    ///
+    /// The rewritten string goes through full normalization and validation,
+    /// so the hash and length are recomputed and a replacement that
+    /// introduces disallowed characters or exceeds `MAX_LENGTH` is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The substring to replace
+    /// * `to` - The replacement
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the rewritten key is invalid for the domain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("schema_v1_users")?;
+    /// assert_eq!(key.replace("v1", "v2")?.as_str(), "schema_v2_users");
+    /// assert!(key.replace("_", " ").is_err());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn replace(&self, from: &str, to: &str) -> Result<Self, KeyParseError> {
+        let replaced = self.inner.replace(from, to);
+        if let Some(same) = self.with_hash_cached_from(&replaced) {
+            return Ok(same);
+        }
+        Self::from_string(replaced)
+    }
+
+    /// Returns a new key built from a closure applied to this key's string
+    ///
+    /// This is the general primitive behind helpers such as
+    /// [`replace`](Self::replace) and [`to_case`](Self::to_case). The
+    /// closure output is not trusted: it goes through the full creation
+    /// pipeline, so it is normalized, checked against every domain rule and
+    /// gets a freshly computed hash and length.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure receiving the current key string
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the closure output is invalid for the domain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_42")?;
+    /// let tagged = key.transform(|s| format!("{s}_archived"))?;
+    /// assert_eq!(tagged.as_str(), "user_42_archived");
+    /// assert!(key.transform(|s| format!("{s}!")).is_err());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn transform<F: FnOnce(&str) -> String>(&self, f: F) -> Result<Self, KeyParseError> {
+        Self::from_string(f(self.as_str()))
+    }
+
+    /// Returns a new key with the given string appended
+    ///
+    /// Unlike [`ensure_suffix`](Self::ensure_suffix), the string is always
+    /// appended. The combined key goes through full validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The string to append
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user")?;
+    /// let versioned = key.concat_str("_v1")?;
+    /// assert_eq!(versioned.as_str(), "user_v1");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the combined key would be invalid or too long
+    pub fn concat_str(&self, suffix: &str) -> Result<Self, KeyParseError> {
+        let mut combined = String::with_capacity(self.len() + suffix.len());
+        combined.push_str(&self.inner);
+        combined.push_str(suffix);
+        Self::from_string(combined)
+    }
+
+    /// Returns the version encoded in a trailing `_v<digits>` suffix
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// assert_eq!(TestKey::new("config_v3")?.version(), Some(3));
+    /// assert_eq!(TestKey::new("config")?.version(), None);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn version(&self) -> Option<u32> {
+        self.version_suffix().map(|(_, version)| version)
+    }
+
+    /// Returns a key with its `_v<digits>` suffix set to `version`
+    ///
+    /// Any existing version suffix is replaced rather than extended, so
+    /// applying this repeatedly yields a single suffix. The resulting key
+    /// goes through full validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version number to encode
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("config")?;
+    /// let v3 = key.with_version(2)?.with_version(3)?;
+    /// assert_eq!(v3.as_str(), "config_v3");
+    /// assert_eq!(v3.version(), Some(3));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the versioned key would be invalid or too long
+    pub fn with_version(&self, version: u32) -> Result<Self, KeyParseError> {
+        use fmt::Write as _;
+
+        let base = match self.version_suffix() {
+            Some((start, _)) => &self.inner[..start],
+            None => self.as_str(),
+        };
+
+        let mut versioned = String::with_capacity(base.len() + 12);
+        // Writing to a String cannot fail
+        let _ = write!(versioned, "{base}_v{version}");
+        Self::from_string(versioned)
+    }
+
+    /// Splits the key into its base and trailing decimal number
+    ///
+    /// Scans backwards over trailing ASCII digits without allocating. The
+    /// base keeps any separator before the digits, so `user_000123` splits
+    /// into `("user_", Some(123))`. Keys without trailing digits, or whose
+    /// digits overflow `u64`, return the whole key and `None`. Use
+    /// [`Key::numeric_suffix_width`] to recover zero-padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_000123")?;
+    /// assert_eq!(key.split_numeric_suffix(), ("user_", Some(123)));
+    /// assert_eq!(key.numeric_suffix_width(), 6);
+    ///
+    /// let plain = TestKey::new("user")?;
+    /// assert_eq!(plain.split_numeric_suffix(), ("user", None));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn split_numeric_suffix(&self) -> (&str, Option<u64>) {
+        let key = self.as_str();
+        let width = self.numeric_suffix_width();
+        if width == 0 {
+            return (key, None);
+        }
+        let (base, digits) = key.split_at(key.len() - width);
+        match digits.parse() {
+            Ok(number) => (base, Some(number)),
+            Err(_) => (key, None),
+        }
+    }
+
+    /// Returns the number of trailing ASCII digits in the key
+    ///
+    /// Together with [`Key::split_numeric_suffix`] this preserves the
+    /// zero-padding of sequence keys such as `item_0009`.
+    #[must_use]
+    pub fn numeric_suffix_width(&self) -> usize {
+        self.inner
+            .bytes()
+            .rev()
+            .take_while(u8::is_ascii_digit)
+            .count()
+    }
+
+    /// Returns the next key in a numeric sequence
+    ///
+    /// The trailing number is incremented with its zero-padding preserved,
+    /// so `item_0009` becomes `item_0010`; the width grows only when the
+    /// number needs more digits (`item_99` becomes `item_100`). A key
+    /// without a numeric suffix gets the domain's default separator and `1`
+    /// appended, so `item` becomes `item_1`. The result goes through full
+    /// validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidStructure` if the trailing number does not fit in a
+    /// `u64` or is `u64::MAX`, or `KeyParseError` if the next key is invalid
+    /// or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// assert_eq!(TestKey::new("item_0009")?.increment()?.as_str(), "item_0010");
+    /// assert_eq!(TestKey::new("item")?.increment()?.as_str(), "item_1");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    pub fn increment(&self) -> Result<Self, KeyParseError> {
+        use fmt::Write as _;
+
+        let width = self.numeric_suffix_width();
+        let mut next = String::with_capacity(self.len() + 2);
+        if width == 0 {
+            // Writing to a String cannot fail
+            let _ = write!(next, "{}{}1", self.inner, T::default_separator());
+        } else {
+            let (base, number) = self.split_numeric_suffix();
+            let number =
+                number
+                    .and_then(|n| n.checked_add(1))
+                    .ok_or(KeyParseError::InvalidStructure {
+                        reason: "numeric suffix overflows u64",
+                    })?;
+            // Writing to a String cannot fail
+            let _ = write!(next, "{base}{number:0width$}");
+        }
+        Self::from_string(next)
+    }
+
+    /// Find a trailing `_v<digits>` suffix, returning its start and value
+    fn version_suffix(&self) -> Option<(usize, u32)> {
+        let key = self.as_str();
+        let start = key.rfind("_v")?;
+        let digits = &key[start + 2..];
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        // A key consisting only of the suffix has no base to keep
+        if start == 0 {
+            return None;
+        }
+        digits.parse().ok().map(|version| (start, version))
+    }
+
+    /// Get validation rules that this key satisfies
+    ///
+    /// Returns detailed information about the validation characteristics
+    /// of this key and its domain, useful for debugging and introspection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    ///     const MAX_LENGTH: usize = 32;
+    ///     const HAS_CUSTOM_VALIDATION: bool = true;
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("example")?;
+    /// let info = key.validation_info();
+    ///
+    /// assert_eq!(info.domain, "test");
+    /// assert_eq!(info.max_length, 32);
+    /// assert_eq!(info.length, 7);
+    /// assert!(info.has_custom_validation);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn validation_info(&self) -> KeyValidationInfo {
+        KeyValidationInfo {
+            domain: T::DOMAIN_NAME,
+            max_length: T::MAX_LENGTH,
+            length: self.len(),
+            has_custom_validation: T::HAS_CUSTOM_VALIDATION,
+            has_custom_normalization: T::HAS_CUSTOM_NORMALIZATION,
+        }
+    }
+
+    /// Get comprehensive information about this key's domain
+    ///
+    /// This is equivalent to calling [`domain_info`](crate::domain_info)
+    /// with the key's domain type, but doesn't require naming the type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    ///     const MAX_LENGTH: usize = 32;
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("example")?;
+    /// let info = key.domain_info();
+    ///
+    /// assert_eq!(info.name, "test");
+    /// assert_eq!(info.max_length, 32);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub fn domain_info(&self) -> DomainInfo {
+        crate::domain::domain_info::<T>()
+    }
+
+    /// Returns a display adapter that prints at most `max` characters
+    ///
+    /// When the key is longer, the output ends with `…(N more)` where `N` is
+    /// the number of characters left out. Truncation always happens on a
+    /// character boundary. This is intended for bounded log fields and does
+    /// not create a new key.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum number of characters of the key to print
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("a_rather_long_key")?;
+    /// assert_eq!(key.display_truncated(8).to_string(), "a_rather…(9 more)");
+    /// assert_eq!(key.display_truncated(64).to_string(), "a_rather_long_key");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn display_truncated(&self, max: usize) -> impl fmt::Display + '_ {
+        TruncatedDisplay {
+            text: self.as_str(),
+            max,
+        }
+    }
+
+    /// Returns the key escaped for embedding in the given context
+    ///
+    /// Keys already restrict their characters, so escaping is usually a
+    /// no-op and the key is returned borrowed. Only when the key contains
+    /// characters that are significant in `ctx` is an owned, quoted copy
+    /// produced. See [`EscapeContext`] for the rules of each context.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Where the escaped string is going to be embedded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{EscapeContext, Key, KeyDomain};
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '\''
+    ///     }
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let plain = TestKey::new("user_name")?;
+    /// assert!(matches!(plain.escape_for(EscapeContext::SqlIdentifier), Cow::Borrowed(_)));
+    ///
+    /// let quoted = TestKey::new("o'brien-id")?;
+    /// assert_eq!(quoted.escape_for(EscapeContext::SqlIdentifier), "\"o'brien-id\"");
+    /// assert_eq!(quoted.escape_for(EscapeContext::Shell), "'o'\\''brien-id'");
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn escape_for(&self, ctx: EscapeContext) -> Cow<'_, str> {
+        let key = self.as_str();
+        match ctx {
+            EscapeContext::SqlIdentifier => {
+                let plain = !key.starts_with(|c: char| c.is_ascii_digit())
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+                if plain {
+                    Cow::Borrowed(key)
+                } else {
+                    Cow::Owned(quote_with(key, '"', |c, out| {
+                        if c == '"' {
+                            out.push('"');
+                        }
+                        out.push(c);
+                    }))
+                }
+            }
+            EscapeContext::Shell => {
+                let plain = key.chars().all(|c| {
+                    c.is_ascii_alphanumeric()
+                        || matches!(c, '_' | '-' | '.' | '/' | ':' | '@' | '+' | '=' | ',')
+                });
+                if plain {
+                    Cow::Borrowed(key)
+                } else {
+                    Cow::Owned(quote_with(key, '\'', |c, out| {
+                        if c == '\'' {
+                            out.push_str("'\\''");
+                        } else {
+                            out.push(c);
+                        }
+                    }))
+                }
+            }
+            EscapeContext::LogField => {
+                let plain = key.chars().all(|c| {
+                    !c.is_whitespace() && !c.is_control() && !matches!(c, '"' | '=' | '\\')
+                });
+                if plain {
+                    Cow::Borrowed(key)
+                } else {
+                    Cow::Owned(quote_with(key, '"', |c, out| match c {
+                        '"' | '\\' => {
+                            out.push('\\');
+                            out.push(c);
+                        }
+                        c if c.is_control() => out.extend(c.escape_default()),
+                        c => out.push(c),
+                    }))
+                }
+            }
+        }
+    }
+
+    /// Returns a display adapter that hides the key content
+    ///
+    /// Prints the domain name followed by a short prefix of the cached hash,
+    /// e.g. `user:#a3f1…`. The output is cheap to produce and deterministic
+    /// for a given build, so redacted log lines can still be correlated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct UserDomain;
+    /// impl KeyDomain for UserDomain {
+    ///     const DOMAIN_NAME: &'static str = "user";
+    /// }
+    /// type UserKey = Key<UserDomain>;
+    ///
+    /// let key = UserKey::new("alice_smith")?;
+    /// let shown = key.redacted().to_string();
+    /// assert!(shown.starts_with("user:#"));
+    /// assert!(!shown.contains("alice"));
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn redacted(&self) -> impl fmt::Display {
+        RedactedDisplay {
+            domain: T::DOMAIN_NAME,
+            hash: self.hash,
+        }
+    }
+
+    /// Checks that no key in the set is a segment prefix of another
+    ///
+    /// A key is a segment prefix of another if the other key starts with it
+    /// followed by the domain's default separator, or if both are equal.
+    /// Prefix-free key sets allow unambiguous range scans over a namespace.
+    ///
+    /// Keys are sorted segment by segment and only neighbours are compared,
+    /// so this runs in `O(n log n)` comparisons.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The key set to check
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let keys = [TestKey::new("user_a")?, TestKey::new("user")?, TestKey::new("order")?];
+    /// assert_eq!(TestKey::is_prefix_free(&keys), Err((1, 0)));
+    ///
+    /// let keys = [TestKey::new("user_a")?, TestKey::new("users")?];
+    /// assert!(TestKey::is_prefix_free(&keys).is_ok());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the indices `(prefix, other)` of the first offending pair found
+    pub fn is_prefix_free(keys: &[Self]) -> Result<(), (usize, usize)> {
+        let separator = T::default_separator();
+        let mut order: Vec<usize> = (0..keys.len()).collect();
+        order.sort_by(|&a, &b| {
+            keys[a]
+                .as_str()
+                .split(separator)
+                .cmp(keys[b].as_str().split(separator))
+        });
+
+        for pair in order.windows(2) {
+            let prefix = keys[pair[0]].as_str();
+            let other = keys[pair[1]].as_str();
+            if other
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(separator))
+            {
+                return Err((pair[0], pair[1]));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// KEY IMPLEMENTATION - ENCODING METHODS
+// ============================================================================
+
+/// Byte used for the domain separator in the index encoding
+const INDEX_SEPARATOR: u8 = 0x00;
+
+/// Escape byte used for content bytes that collide with the separator byte
+const INDEX_ESCAPE: u8 = 0x01;
+
+impl<T: KeyDomain> Key<T> {
+    /// Returns an order-preserving byte encoding of the key
+    ///
+    /// The encoding sorts segment by segment, which is what range scans over
+    /// hierarchical keys in ordered KV stores expect: `a/b` sorts before
+    /// `a/bc` and `a/b/c`, and all of them sort before `a-b`. The domain's
+    /// separator is encoded as `0x00`, while content bytes `0x00` and `0x01`
+    /// are escaped as `0x01 0x01` and `0x01 0x02`. All other bytes are copied
+    /// verbatim, so keys without separators encode to their own bytes.
+    ///
+    /// Use [`from_index_bytes`](Self::from_index_bytes) to decode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct PathDomain;
+    /// impl KeyDomain for PathDomain {
+    ///     const DOMAIN_NAME: &'static str = "path";
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         c.is_ascii_alphanumeric() || c == '/' || c == '-'
+    ///     }
+    ///     fn default_separator() -> char {
+    ///         '/'
+    ///     }
+    /// }
+    /// type PathKey = Key<PathDomain>;
+    ///
+    /// let short = PathKey::new("a/b")?;
+    /// let long = PathKey::new("a/bc")?;
+    /// let dashed = PathKey::new("a-b")?;
+    ///
+    /// assert!(short.index_bytes() < long.index_bytes());
+    /// assert!(long.index_bytes() < dashed.index_bytes());
+    /// assert_eq!(PathKey::from_index_bytes(&long.index_bytes())?, long);
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn index_bytes(&self) -> Vec<u8> {
+        let separator = T::default_separator();
+        let mut out = Vec::with_capacity(self.len() + 1);
+        let mut buf = [0u8; 4];
+
+        for c in self.chars() {
+            if c == separator {
+                out.push(INDEX_SEPARATOR);
+                continue;
+            }
+            for &byte in c.encode_utf8(&mut buf).as_bytes() {
+                match byte {
+                    INDEX_SEPARATOR => out.extend_from_slice(&[INDEX_ESCAPE, 0x01]),
+                    INDEX_ESCAPE => out.extend_from_slice(&[INDEX_ESCAPE, 0x02]),
+                    other => out.push(other),
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Decodes a key from its order-preserving byte encoding
+    ///
+    /// This is the inverse of [`index_bytes`](Self::index_bytes). The decoded
+    /// string is fully validated for the domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Bytes previously produced by `index_bytes`
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::InvalidStructure` if the bytes contain an
+    /// invalid escape sequence, `InvalidUtf8` with the offset into the
+    /// decoded key if they do not decode to UTF-8, or any validation error
+    /// for the decoded key
+    pub fn from_index_bytes(bytes: &[u8]) -> Result<Self, KeyParseError> {
+        let separator = T::default_separator();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut buf = [0u8; 4];
+        let mut iter = bytes.iter();
+
+        while let Some(&byte) = iter.next() {
+            match byte {
+                INDEX_SEPARATOR => {
+                    decoded.extend_from_slice(separator.encode_utf8(&mut buf).as_bytes());
+                }
+                INDEX_ESCAPE => match iter.next() {
+                    Some(0x01) => decoded.push(INDEX_SEPARATOR),
+                    Some(0x02) => decoded.push(INDEX_ESCAPE),
+                    _ => {
+                        return Err(KeyParseError::InvalidStructure {
+                            reason: "invalid escape sequence in index bytes",
+                        })
+                    }
+                },
+                other => decoded.push(other),
+            }
+        }
+
+        let key = String::from_utf8(decoded).map_err(|e| KeyParseError::InvalidUtf8 {
+            position: e.utf8_error().valid_up_to(),
+        })?;
+        Self::from_string(key)
+    }
+
+    /// Writes the key into a buffer as a length-prefixed record
+    ///
+    /// The record is the key length as a little-endian `u32` followed by the
+    /// key bytes. Nothing is allocated. Use [`read_from`](Self::read_from) to
+    /// decode.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Destination buffer
+    ///
+    /// # Returns
+    ///
+    /// * `Some(n)` with the number of bytes written
+    /// * `None` if `buf` is too small, in which case nothing is written
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{Key, KeyDomain};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    /// type TestKey = Key<TestDomain>;
+    ///
+    /// let key = TestKey::new("user_1")?;
+    /// let mut buf = [0u8; 32];
+    /// let written = key.write_to(&mut buf).unwrap();
+    /// assert_eq!(written, 4 + 6);
+    ///
+    /// let (decoded, read) = TestKey::read_from(&buf)?;
+    /// assert_eq!(decoded, key);
+    /// assert_eq!(read, written);
+    /// assert!(key.write_to(&mut [0u8; 8]).is_none());
+    /// # Ok::<(), domain_key::KeyParseError>(())
+    /// ```
+    #[must_use]
+    pub fn write_to(&self, buf: &mut [u8]) -> Option<usize> {
+        let total = 4 + self.len();
+        let out = buf.get_mut(..total)?;
+        out[..4].copy_from_slice(&self.length.to_le_bytes());
+        out[4..].copy_from_slice(self.inner.as_bytes());
+        Some(total)
+    }
+
+    /// Reads a key from a length-prefixed record
+    ///
+    /// This is the inverse of [`write_to`](Self::write_to). The key is fully
+    /// validated. Bytes after the record are ignored, so records can be read
+    /// back to back using the returned length.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Bytes starting with a record produced by `write_to`
+    ///
+    /// # Returns
+    ///
+    /// The decoded key and the number of bytes consumed
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::InvalidStructure` if the record is truncated,
+    /// `InvalidUtf8` with the offset into the key content if it is not UTF-8,
+    /// `TooLong` if the declared length exceeds the domain limit, or any
+    /// validation error for the decoded key
+    pub fn read_from(buf: &[u8]) -> Result<(Self, usize), KeyParseError> {
+        let prefix: [u8; 4] = buf.get(..4).and_then(|bytes| bytes.try_into().ok()).ok_or(
+            KeyParseError::InvalidStructure {
+                reason: "buffer too short for key length prefix",
+            },
+        )?;
+        let length = u32::from_le_bytes(prefix) as usize;
+
+        if length > T::MAX_LENGTH {
+            return Err(KeyParseError::TooLong {
+                max_length: T::MAX_LENGTH,
+                actual_length: length,
+            });
+        }
+
+        let content = buf
+            .get(4..4 + length)
+            .ok_or(KeyParseError::InvalidStructure {
+                reason: "buffer too short for key content",
+            })?;
+        let key = core::str::from_utf8(content).map_err(|e| KeyParseError::InvalidUtf8 {
+            position: e.valid_up_to(),
+        })?;
+
+        Ok((Self::new(key)?, 4 + length))
+    }
+}
+
+// ============================================================================
+// KEY IMPLEMENTATION - HELPER METHODS
+// ============================================================================
+
+impl<T: KeyDomain> Key<T> {
+    /// Run the domain's rules on content that is already normalized
+    ///
+    /// Used by paths that assemble keys from validated pieces. Goes through
+    /// [`KeyDomain::canonicalize`] so domains that only override that hook
+    /// are still checked, and returns the canonical form, which callers must
+    /// store so the result matches what `Key::new` would produce.
+    fn check_domain_rules(key: &str) -> Result<Cow<'_, str>, KeyParseError> {
+        T::canonicalize(Cow::Borrowed(key)).map_err(Self::fix_domain_error)
+    }
+
+    /// Fix domain name in domain validation errors
+    ///
+    /// This helper ensures that domain validation errors have the correct
+    /// domain name, even when they're created generically.
+    #[inline]
+    fn fix_domain_error(e: KeyParseError) -> KeyParseError {
+        match e {
+            KeyParseError::DomainValidation { message, .. } => KeyParseError::DomainValidation {
+                domain: T::DOMAIN_NAME,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    /// Returns a clone of this key if `candidate` has exactly the same content
+    ///
+    /// Transformations that may turn out to be no-ops use this to skip
+    /// revalidation and keep the cached hash instead of recomputing it.
+    #[inline]
+    fn with_hash_cached_from(&self, candidate: &str) -> Option<Self> {
+        (self.as_str() == candidate).then(|| self.clone())
+    }
+
+    /// Finish key creation from a validated, normalized string
+    ///
+    /// Computes the cached hash and length, then runs the domain's
+    /// hash-aware validation. All validated creation paths end here.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if `post_validate` rejects the key or its
+    /// length doesn't fit the cached length field
+    fn finalize(inner: Storage) -> Result<Self, KeyParseError> {
+        let hash = Self::compute_hash(&inner);
+        T::post_validate(&inner, hash).map_err(Self::fix_domain_error)?;
+
+        let length = u32::try_from(inner.len()).map_err(|_| KeyParseError::TooLong {
+            max_length: u32::MAX as usize,
+            actual_length: inner.len(),
+        })?;
+
+        T::on_create(&inner);
+
+        Ok(Self {
+            parts: PartPositions::record::<T>(&inner),
+            inner,
+            hash,
+            length,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Common validation pipeline
+    ///
+    /// Performs validation that's common to all domains: length checking,
+    /// character validation, and structural validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
+    pub(crate) fn validate_common<D: KeyDomain>(key: &str) -> Result<(), KeyParseError> {
+        let trimmed = key.trim();
+
+        if trimmed.is_empty() {
+            return Err(KeyParseError::Empty);
+        }
+
+        if trimmed.len() > D::MAX_LENGTH {
+            return Err(KeyParseError::TooLong {
+                max_length: D::MAX_LENGTH,
+                actual_length: trimmed.len(),
+            });
+        }
+
+        if trimmed.len() < D::min_length() {
+            return Err(KeyParseError::TooShort {
+                min_length: D::min_length(),
+                actual_length: trimmed.len(),
+            });
+        }
+
+        // Reject non-ASCII input before the byte-wise path sees it
+        if D::ASCII_ONLY && !trimmed.is_ascii() {
+            if let Some((position, character)) = trimmed.char_indices().find(|(_, c)| !c.is_ascii())
+            {
+                return Err(KeyParseError::InvalidCharacter {
+                    character,
+                    position,
+                    expected: Some("ASCII character"),
+                });
+            }
+        }
+
+        // Use fast validation
+        Self::validate_fast::<D>(trimmed)
+    }
+
+    /// Fast validation path using optimized algorithms
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if the prefixed key would be invalid or too long
+    fn validate_fast<D: KeyDomain>(key: &str) -> Result<(), KeyParseError> {
+        // Pure ASCII input never needs UTF-8 decoding, whatever the domain
+        if D::ASCII_ONLY || key.is_ascii() {
+            return Self::validate_ascii_bytes::<D>(key);
+        }
+
+        let mut chars = key.char_indices();
+        let mut prev_char = None;
+
+        // Validate first character
+        if let Some((pos, first)) = chars.next() {
+            let char_allowed = D::allowed_start_character(first);
+
+            if !char_allowed {
+                return Err(KeyParseError::InvalidCharacter {
+                    character: first,
+                    position: pos,
+                    expected: Some("allowed by domain"),
+                });
+            }
+
+            prev_char = Some(first);
+        }
+
+        // Validate remaining characters
+        for (pos, c) in chars {
+            let char_allowed = D::allowed_characters(c);
+
+            if !char_allowed {
+                return Err(KeyParseError::InvalidCharacter {
+                    character: c,
+                    position: pos,
+                    expected: Some("allowed by domain"),
+                });
+            }
+
+            if let Some(prev) = prev_char {
+                if !D::allowed_consecutive_characters(prev, c) {
+                    return Err(KeyParseError::InvalidStructure {
+                        reason: "consecutive characters not allowed",
+                    });
+                }
+            }
+            prev_char = Some(c);
+        }
+
+        // Check last character
+        if let Some(last) = prev_char {
+            if !D::allowed_end_character(last) {
+                return Err(KeyParseError::InvalidStructure {
+                    reason: "invalid end character",
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Byte-wise validation path for ASCII input
+    ///
+    /// Applies the same rules as `validate_fast` without decoding UTF-8.
+    /// Callers guarantee the input is ASCII, either because the domain is
+    /// `ASCII_ONLY` (and `validate_common` has rejected anything else) or
+    /// because `is_ascii` was checked.
+    fn validate_ascii_bytes<D: KeyDomain>(key: &str) -> Result<(), KeyParseError> {
+        let mut prev_char = None;
+
+        for (pos, &byte) in key.as_bytes().iter().enumerate() {
+            let c = char::from(byte);
+            let char_allowed = match prev_char {
+                None => D::allowed_start_character(c),
+                Some(_) => D::allowed_characters(c),
+            };
+
+            if !char_allowed {
                 return Err(KeyParseError::InvalidCharacter {
-                    character: first,
+                    character: c,
                     position: pos,
                     expected: Some("allowed by domain"),
                 });
             }
 
-            prev_char = Some(first);
+            if let Some(prev) = prev_char {
+                if !D::allowed_consecutive_characters(prev, c) {
+                    return Err(KeyParseError::InvalidStructure {
+                        reason: "consecutive characters not allowed",
+                    });
+                }
+            }
+            prev_char = Some(c);
+        }
+
+        if let Some(last) = prev_char {
+            if !D::allowed_end_character(last) {
+                return Err(KeyParseError::InvalidStructure {
+                    reason: "invalid end character",
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Normalize a borrowed string
+    pub(crate) fn normalize<D: KeyDomain>(key: &str) -> Cow<'_, str> {
+        // Apply domain-specific normalization
+        D::normalize_domain(Self::normalize_common::<D>(key))
+    }
+
+    /// Common normalization shared by every domain: trimming and lowercasing
+    pub(crate) fn normalize_common<D: KeyDomain>(key: &str) -> Cow<'_, str> {
+        let trimmed = key.trim();
+
+        let needs_lowercase =
+            D::CASE_INSENSITIVE && trimmed.chars().any(|c| c.is_ascii_uppercase());
+
+        if needs_lowercase {
+            Cow::Owned(trimmed.to_ascii_lowercase())
+        } else if trimmed.len() != key.len() {
+            // Only trimming was needed
+            Cow::Owned(trimmed.to_string())
+        } else {
+            // No changes needed
+            Cow::Borrowed(trimmed)
+        }
+    }
+
+    /// Apply common normalization to an owned string efficiently
+    fn normalize_owned<D: KeyDomain>(mut key: String) -> String {
+        // In-place operations when possible
+        let trimmed = key.trim();
+        if trimmed.len() != key.len() {
+            key = trimmed.to_string();
+        }
+
+        if D::CASE_INSENSITIVE {
+            key.make_ascii_lowercase();
+        }
+        key
+    }
+
+    /// Compute hash using the configured algorithm
+    ///
+    /// The hash algorithm is selected at compile time based on feature flags,
+    /// allowing for different performance/security trade-offs.
+    #[allow(clippy::needless_return)]
+    pub(crate) fn compute_hash(key: &str) -> u64 {
+        if key.is_empty() {
+            return 0;
+        }
+
+        // Priority-based selection: fast > secure > crypto > default
+        // Use early returns to avoid unreachable code warnings
+
+        // 1. Fast feature: GxHash with AHash fallback
+        #[cfg(feature = "fast")]
+        {
+            // Try GxHash on supported platforms. The selection has to stay
+            // compile-time: the gxhash crate refuses to build unless AES
+            // intrinsics are enabled for the whole binary, so a runtime
+            // `is_x86_feature_detected!` dispatch has nothing to call into.
+            #[cfg(any(
+                all(target_arch = "x86_64", target_feature = "aes"),
+                all(
+                    target_arch = "aarch64",
+                    target_feature = "aes",
+                    target_feature = "neon"
+                )
+            ))]
+            {
+                gxhash::gxhash64(key.as_bytes(), 0)
+            }
+
+            // Fallback to AHash when GxHash requirements not met
+            #[cfg(not(any(
+                all(target_arch = "x86_64", target_feature = "aes"),
+                all(
+                    target_arch = "aarch64",
+                    target_feature = "aes",
+                    target_feature = "neon"
+                )
+            )))]
+            {
+                use core::hash::Hasher;
+                let mut hasher = ahash::AHasher::default();
+                hasher.write(key.as_bytes());
+                return hasher.finish();
+            }
+        }
+
+        // 2. Secure feature: AHash for DoS resistance, seeded per process
+        #[cfg(all(feature = "secure", not(feature = "fast"), feature = "std"))]
+        {
+            return crate::features::seeded_hash(crate::features::hash_seed(), key.as_bytes());
+        }
+
+        #[cfg(all(feature = "secure", not(feature = "fast"), not(feature = "std")))]
+        {
+            use core::hash::Hasher;
+            let mut hasher = ahash::AHasher::default();
+            hasher.write(key.as_bytes());
+            return hasher.finish();
+        }
+
+        // 3. Crypto feature: Blake3 for cryptographic security
+        #[cfg(all(feature = "crypto", not(any(feature = "fast", feature = "secure"))))]
+        {
+            let hash = blake3::hash(key.as_bytes());
+            let bytes = hash.as_bytes();
+            return u64::from_le_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]);
+        }
+
+        // 4. Default: Standard library hasher or FNV-1a
+        #[cfg(not(any(feature = "fast", feature = "secure", feature = "crypto")))]
+        {
+            #[cfg(feature = "std")]
+            {
+                use core::hash::Hasher;
+                use std::collections::hash_map::DefaultHasher;
+                let mut hasher = DefaultHasher::new();
+                hasher.write(key.as_bytes());
+                return hasher.finish();
+            }
+
+            #[cfg(not(feature = "std"))]
+            {
+                return Self::fnv1a_hash(key.as_bytes());
+            }
+        }
+    }
+
+    /// FNV-1a hash implementation, used for `no_std` and `stable_hash`
+    fn fnv1a_hash(bytes: &[u8]) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for &byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        hash
+    }
+}
+
+// ============================================================================
+// SUPPORTING TYPES
+// ============================================================================
+
+/// Information about a key's validation characteristics
+///
+/// This structure provides detailed information about how a key was validated
+/// and what domain-specific features are enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValidationInfo {
+    /// Domain name
+    pub domain: &'static str,
+    /// Maximum allowed length for this domain
+    pub max_length: usize,
+    /// Actual length of the key
+    pub length: usize,
+    /// Whether the domain has custom validation rules
+    pub has_custom_validation: bool,
+    /// Whether the domain has custom normalization rules
+    pub has_custom_normalization: bool,
+}
+
+/// Naming conventions supported by [`Key::to_case`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseStyle {
+    /// `snake_case`
+    Snake,
+    /// `kebab-case`
+    Kebab,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+}
+
+/// Target contexts for [`Key::escape_for`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeContext {
+    /// SQL identifier; quoted with `"` (doubling embedded quotes) unless it
+    /// consists of lowercase ASCII letters, digits and `_` and does not
+    /// start with a digit
+    SqlIdentifier,
+    /// POSIX shell argument; single-quoted unless it only contains
+    /// characters the shell never interprets
+    Shell,
+    /// Value in a `key=value` log line; double-quoted with `\\` escapes if it
+    /// contains whitespace, control characters, `"`, `=` or `\\`
+    LogField,
+}
+
+/// Wraps `text` in `quote`, passing each character through `escape`
+fn quote_with(text: &str, quote: char, mut escape: impl FnMut(char, &mut String)) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push(quote);
+    for c in text.chars() {
+        escape(c, &mut out);
+    }
+    out.push(quote);
+    out
+}
+
+/// Display adapter returned by [`Key::display_truncated`]
+struct TruncatedDisplay<'a> {
+    text: &'a str,
+    max: usize,
+}
+
+impl fmt::Display for TruncatedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.text.char_indices().nth(self.max) {
+            Some((end, _)) => {
+                let omitted = self.text[end..].chars().count();
+                write!(f, "{}…({omitted} more)", &self.text[..end])
+            }
+            None => f.write_str(self.text),
+        }
+    }
+}
+
+/// Display adapter returned by [`Key::redacted`]
+struct RedactedDisplay {
+    domain: &'static str,
+    hash: u64,
+}
+
+impl fmt::Display for RedactedDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:#{:04x}…", self.domain, self.hash >> 48)
+    }
+}
+
+/// Split a key into words for case conversion
+///
+/// Words end at `_`, `-` and `.` separators and before an uppercase letter
+/// that follows a lowercase letter or digit.
+fn case_words(s: &str) -> Vec<&str> {
+    let mut start = 0;
+    let mut prev: Option<char> = None;
+    let mut splits = Vec::new();
+
+    for (pos, c) in s.char_indices() {
+        if matches!(c, '_' | '-' | '.') {
+            splits.push(&s[start..pos]);
+            start = pos + c.len_utf8();
+        } else if c.is_ascii_uppercase()
+            && prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+        {
+            splits.push(&s[start..pos]);
+            start = pos;
+        }
+        prev = Some(c);
+    }
+    splits.push(&s[start..]);
+
+    splits.retain(|word| !word.is_empty());
+    splits
+}
+
+// ============================================================================
+// KEY HASHER
+// ============================================================================
+
+/// Hasher that makes `Key<T>` and `str` hash identically
+///
+/// A `Key<T>` writes its cached hash, which this hasher passes through
+/// unchanged. A `str` writes its bytes, which this hasher runs through the
+/// same algorithm used to compute the cached hash; the `0xff` terminator
+/// that `str` appends is ignored. As a result a key and its string content
+/// produce the same hash.
+///
+/// Only use this hasher for maps keyed by `Key<T>`. Other key types would
+/// have every write but the last discarded.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyHasher {
+    hash: u64,
+}
+
+impl Hasher for KeyHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash = match core::str::from_utf8(bytes) {
+            Ok(text) => Key::<crate::domain::DefaultDomain>::compute_hash(text),
+            Err(_) => Key::<crate::domain::DefaultDomain>::fnv1a_hash(bytes),
+        };
+    }
+
+    #[inline]
+    fn write_u8(&mut self, _terminator: u8) {}
+
+    #[inline]
+    fn write_u64(&mut self, cached: u64) {
+        self.hash = cached;
+    }
+}
+
+/// `BuildHasher` producing [`KeyHasher`]s
+pub type BuildKeyHasher = core::hash::BuildHasherDefault<KeyHasher>;
+
+/// Lookup support for querying key maps by string content
+///
+/// `Key<T>` borrows as `dyn KeyContent`, whose `Hash` writes the same cached
+/// `u64` as `Key<T>` itself and whose `Eq` compares content. A `&str` query
+/// computes that `u64` on the fly, so lookups agree with the stored keys
+/// under every hasher. The module is private, so the trait cannot be named
+/// or implemented outside the crate.
+mod lookup {
+    pub trait KeyContent {
+        fn content(&self) -> &str;
+        fn content_hash(&self) -> u64;
+    }
+}
+
+impl<T: KeyDomain> lookup::KeyContent for Key<T> {
+    #[inline]
+    fn content(&self) -> &str {
+        self.as_str()
+    }
+
+    #[inline]
+    fn content_hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl lookup::KeyContent for &str {
+    #[inline]
+    fn content(&self) -> &str {
+        self
+    }
+
+    #[inline]
+    fn content_hash(&self) -> u64 {
+        Key::<crate::domain::DefaultDomain>::compute_hash(self)
+    }
+}
+
+impl Hash for dyn lookup::KeyContent + '_ {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.content_hash());
+    }
+}
+
+impl PartialEq for dyn lookup::KeyContent + '_ {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.content() == other.content()
+    }
+}
+
+impl Eq for dyn lookup::KeyContent + '_ {}
+
+impl<'a, T: KeyDomain> Borrow<dyn lookup::KeyContent + 'a> for Key<T> {
+    #[inline]
+    fn borrow(&self) -> &(dyn lookup::KeyContent + 'a) {
+        self
+    }
+}
+
+/// Hash map keyed by `Key<T>` that can also be queried with `&str`
+///
+/// Dereferences to the underlying `HashMap`, so the usual map API is
+/// available with `Key<T>` arguments. The `*_str` methods additionally find
+/// entries by string content without constructing a key. The query must
+/// already be in normalized form, since no normalization happens on lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{Key, KeyDomain, KeyMap};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct UserDomain;
+/// impl KeyDomain for UserDomain {
+///     const DOMAIN_NAME: &'static str = "user";
+/// }
+/// type UserKey = Key<UserDomain>;
+///
+/// let mut scores: KeyMap<UserDomain, u32> = KeyMap::default();
+/// scores.insert(UserKey::new("alice")?, 10);
+///
+/// assert_eq!(scores.get_str("alice"), Some(&10));
+/// assert_eq!(scores.get(&UserKey::new("alice")?), Some(&10));
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+///
+/// `Key<T>` deliberately does not implement `Borrow<str>`: its `Hash` writes
+/// the cached value, not the bytes, so plain `HashMap::get("...")` would
+/// compile and silently miss.
+///
+/// ```rust,compile_fail
+/// use std::collections::HashMap;
+/// use domain_key::{DefaultDomain, Key};
+///
+/// let map: HashMap<Key<DefaultDomain>, u32> = HashMap::new();
+/// map.get("alice");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMap<T: KeyDomain, V>(std::collections::HashMap<Key<T>, V, BuildKeyHasher>);
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, V> KeyMap<T, V> {
+    /// Creates an empty map
+    #[must_use]
+    pub fn new() -> Self {
+        Self(std::collections::HashMap::default())
+    }
+
+    /// Returns the value for the key with content `key`
+    #[must_use]
+    pub fn get_str(&self, key: &str) -> Option<&V> {
+        self.0.get(&key as &dyn lookup::KeyContent)
+    }
+
+    /// Returns a mutable reference to the value for the key with content `key`
+    pub fn get_str_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.0.get_mut(&key as &dyn lookup::KeyContent)
+    }
+
+    /// Returns `true` if the map contains a key with content `key`
+    #[must_use]
+    pub fn contains_str(&self, key: &str) -> bool {
+        self.0.contains_key(&key as &dyn lookup::KeyContent)
+    }
+
+    /// Removes the entry for the key with content `key`, returning its value
+    pub fn remove_str(&mut self, key: &str) -> Option<V> {
+        self.0.remove(&key as &dyn lookup::KeyContent)
+    }
+
+    /// Unwraps the underlying `HashMap`
+    #[must_use]
+    pub fn into_inner(self) -> std::collections::HashMap<Key<T>, V, BuildKeyHasher> {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, V> Default for KeyMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, V> core::ops::Deref for KeyMap<T, V> {
+    type Target = std::collections::HashMap<Key<T>, V, BuildKeyHasher>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, V> core::ops::DerefMut for KeyMap<T, V> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, V> FromIterator<(Key<T>, V)> for KeyMap<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Key<T>, V)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, V> IntoIterator for KeyMap<T, V> {
+    type Item = (Key<T>, V);
+    type IntoIter = std::collections::hash_map::IntoIter<Key<T>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// ============================================================================
+// INTERNER
+// ============================================================================
+
+/// Deduplicating store for recurring keys
+///
+/// Every call to [`Interner::intern`] validates its input like `Key::new`
+/// and then returns a clone of the first key seen with the same content.
+/// Entries are bucketed by the cached hash and compared by content, so hash
+/// collisions never merge distinct keys.
+///
+/// Every key returned for the same content points at one `Arc<str>`
+/// allocation. The interner therefore requires the `shared` feature: without
+/// it keys own their content inline and could not share storage.
+///
+/// Each distinct key also gets a dense numeric id in insertion order, so hot
+/// lookups can index into a plain array with [`Interner::intern_id`] and map
+/// back with [`Interner::from_intern_id`]. The ids live on the interner
+/// rather than on [`Key`] because they are only meaningful relative to the
+/// table that assigned them.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "shared")]
+/// # {
+/// use domain_key::{Interner, KeyDomain};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TenantDomain;
+/// impl KeyDomain for TenantDomain {
+///     const DOMAIN_NAME: &'static str = "tenant";
+/// }
+///
+/// let mut tenants = Interner::<TenantDomain>::new();
+/// let a = tenants.intern("acme")?;
+/// let b = tenants.intern("ACME")?;
+/// assert_eq!(a, b);
+/// assert_eq!(tenants.len(), 1);
+/// assert!(tenants.intern("not valid").is_err());
+///
+/// let id = tenants.intern_id(&a).unwrap();
+/// assert_eq!(id, 0);
+/// assert_eq!(tenants.from_intern_id(id), Some(&a));
+/// # }
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+#[cfg(all(feature = "std", feature = "shared"))]
+#[derive(Debug, Clone)]
+pub struct Interner<T: KeyDomain> {
+    keys: Vec<Key<T>>,
+    buckets: std::collections::HashMap<u64, Vec<u32>, BuildKeyHasher>,
+}
+
+#[cfg(all(feature = "std", feature = "shared"))]
+impl<T: KeyDomain> Interner<T> {
+    /// Creates an empty interner
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            buckets: std::collections::HashMap::default(),
+        }
+    }
+
+    /// Validates `s` and returns the interned key for its content
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError` if `s` is not a valid key for the domain, or
+    /// `KeyParseError::InvalidStructure` if `u32::MAX` distinct keys are
+    /// already interned and no id is left for a new one
+    pub fn intern(&mut self, s: &str) -> Result<Key<T>, KeyParseError> {
+        let key = Key::<T>::new(s)?;
+        if let Some(id) = self.find_id(key.hash(), key.as_str()) {
+            return Ok(self.keys[id as usize].clone());
+        }
+        let id = u32::try_from(self.keys.len()).map_err(|_| KeyParseError::InvalidStructure {
+            reason: "interner id space exhausted",
+        })?;
+        self.buckets.entry(key.hash()).or_default().push(id);
+        self.keys.push(key.clone());
+        Ok(key)
+    }
+
+    /// Returns the interned key for already-normalized content, if any
+    #[must_use]
+    pub fn get(&self, s: &str) -> Option<&Key<T>> {
+        let id = self.find_id(Key::<T>::compute_hash(s), s)?;
+        self.keys.get(id as usize)
+    }
+
+    /// Returns the id of `key` if it was interned here
+    ///
+    /// Ids are dense and assigned in insertion order starting at 0, so they
+    /// can be used directly as indices into a `Vec` of per-key data.
+    #[must_use]
+    pub fn intern_id(&self, key: &Key<T>) -> Option<u32> {
+        self.find_id(key.hash(), key.as_str())
+    }
+
+    /// Returns the interned key with the given id, if any
+    #[must_use]
+    pub fn from_intern_id(&self, id: u32) -> Option<&Key<T>> {
+        self.keys.get(id as usize)
+    }
+
+    /// Returns the number of distinct keys stored
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if no keys have been interned
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Looks up the id for content with the given cached hash
+    fn find_id(&self, hash: u64, s: &str) -> Option<u32> {
+        self.buckets
+            .get(&hash)?
+            .iter()
+            .copied()
+            .find(|&id| self.keys[id as usize].as_str() == s)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "shared"))]
+impl<T: KeyDomain> Default for Interner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// REHASHING WRAPPER
+// ============================================================================
+
+/// Key wrapper that hashes the key content instead of the cached hash
+///
+/// `Key<T>` feeds only its pre-computed hash to the hasher, which defeats
+/// collections configured with a custom `BuildHasher` that expects to see
+/// the key bytes. `RehashKey<T>` hashes exactly like `str`, so the configured
+/// hasher processes the full content and lookups by `&str` work through
+/// the `Borrow<str>` implementation. The same applies to order-preserving
+/// maps such as `indexmap::IndexMap`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use domain_key::{Key, KeyDomain, RehashKey};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+/// type TestKey = Key<TestDomain>;
+///
+/// let mut map = HashMap::new();
+/// map.insert(RehashKey::new(TestKey::new("user_1")?), 42);
+///
+/// assert_eq!(map.get("user_1"), Some(&42));
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RehashKey<T: KeyDomain>(Key<T>);
+
+impl<T: KeyDomain> RehashKey<T> {
+    /// Wraps a key so that its content is rehashed by the map's hasher
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Key<T>) -> Self {
+        Self(key)
+    }
+
+    /// Returns a reference to the wrapped key
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &Key<T> {
+        &self.0
+    }
+
+    /// Unwraps the wrapper, returning the inner key
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Key<T> {
+        self.0
+    }
+}
+
+impl<T: KeyDomain> Clone for RehashKey<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: KeyDomain> Hash for RehashKey<T> {
+    /// Hashes the key content exactly like `str` does
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_str().hash(state);
+    }
+}
+
+impl<T: KeyDomain> Borrow<str> for RehashKey<T> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<T: KeyDomain> From<Key<T>> for RehashKey<T> {
+    #[inline]
+    fn from(key: Key<T>) -> Self {
+        Self(key)
+    }
+}
+
+impl<T: KeyDomain> fmt::Display for RehashKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+// ============================================================================
+// COMPACT SERIALIZATION WRAPPER
+// ============================================================================
+
+/// Key wrapper that also serializes the cached hash and length
+///
+/// Human-readable formats still see a bare string. Binary formats store
+/// `(content, hash, length)`. With the `trusted` feature enabled, loading
+/// skips validation and only checks the stored length. Without `trusted`
+/// the content is validated as usual.
+///
+/// The stored hash is never reused: it depends on the hash algorithm and,
+/// with `secure`, on the per-process seed, so a hash written by another
+/// build or process would break `HashMap` lookups. It is recomputed on load.
+///
+/// Only enable `trusted` when the stored content was written by this crate,
+/// since invalid content is not rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{CompactKey, Key, KeyDomain};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+/// type TestKey = Key<TestDomain>;
+///
+/// let key = CompactKey::new(TestKey::new("user_1")?);
+/// let json = serde_json::to_string(&key).unwrap();
+/// assert_eq!(json, r#""user_1""#);
+///
+/// let back: CompactKey<TestDomain> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back, key);
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompactKey<T: KeyDomain>(Key<T>);
+
+#[cfg(feature = "serde")]
+impl<T: KeyDomain> CompactKey<T> {
+    /// Wraps a key for compact serialization
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Key<T>) -> Self {
+        Self(key)
+    }
+
+    /// Returns a reference to the wrapped key
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &Key<T> {
+        &self.0
+    }
+
+    /// Unwraps the wrapper, returning the inner key
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Key<T> {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: KeyDomain> Clone for CompactKey<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: KeyDomain> From<Key<T>> for CompactKey<T> {
+    #[inline]
+    fn from(key: Key<T>) -> Self {
+        Self(key)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: KeyDomain> Serialize for CompactKey<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            self.0.serialize(serializer)
+        } else {
+            (self.0.as_str(), self.0.hash, self.0.length).serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: KeyDomain> Deserialize<'de> for CompactKey<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            Key::deserialize(deserializer).map(Self)
+        } else {
+            let (content, hash, length) = <(String, u64, u32)>::deserialize(deserializer)?;
+            Key::from_compact_parts(content, hash, length)
+                .map(Self)
+                .map_err(|e| serde::de::Error::custom(e.to_string()))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: KeyDomain> Key<T> {
+    /// Rebuild a key from its compact binary representation
+    ///
+    /// The stored length is always checked against the content. With the
+    /// `trusted` feature validation is skipped, otherwise the content goes
+    /// through full validation. The stored hash is discarded in both cases
+    /// and recomputed for this process.
+    fn from_compact_parts(content: String, hash: u64, length: u32) -> Result<Self, KeyParseError> {
+        if u32::try_from(content.len()).ok() != Some(length) {
+            return Err(KeyParseError::InvalidStructure {
+                reason: "stored length does not match key content",
+            });
+        }
+
+        let _ = hash;
+
+        #[cfg(feature = "trusted")]
+        {
+            Ok(Self {
+                hash: Self::compute_hash(&content),
+                parts: PartPositions::record::<T>(&content),
+                inner: Storage::from(content),
+                length,
+                _marker: PhantomData,
+            })
+        }
+
+        #[cfg(not(feature = "trusted"))]
+        {
+            Self::from_string(content)
+        }
+    }
+}
+
+// ============================================================================
+// STANDARD TRAIT IMPLEMENTATIONS
+// ============================================================================
+
+/// Display implementation shows domain and key
+///
+/// Domains with `REDACT_IN_DISPLAY` set show the redacted form instead.
+impl<T: KeyDomain> fmt::Display for Key<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if T::REDACT_IN_DISPLAY {
+            return fmt::Display::fmt(&self.redacted(), f);
+        }
+        write!(f, "{}:{}", T::DOMAIN_NAME, self.inner)
+    }
+}
+
+/// String comparisons normalize the string first
+///
+/// A key equals a string when the string normalizes to the key's content
+/// under the domain's rules, so `" User "` equals a case-insensitive key
+/// `user`. No validation is performed on the string.
+impl<T: KeyDomain> Key<T> {
+    #[inline]
+    fn eq_normalized(&self, other: &str) -> bool {
+        Self::normalize::<T>(other) == self.as_str()
+    }
+}
+
+impl<T: KeyDomain> PartialEq<str> for Key<T> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.eq_normalized(other)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<&str> for Key<T> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_normalized(other)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<String> for Key<T> {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.eq_normalized(other)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<Cow<'_, str>> for Key<T> {
+    #[inline]
+    fn eq(&self, other: &Cow<'_, str>) -> bool {
+        self.eq_normalized(other)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<Key<T>> for str {
+    #[inline]
+    fn eq(&self, other: &Key<T>) -> bool {
+        other.eq_normalized(self)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<Key<T>> for &str {
+    #[inline]
+    fn eq(&self, other: &Key<T>) -> bool {
+        other.eq_normalized(self)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<Key<T>> for String {
+    #[inline]
+    fn eq(&self, other: &Key<T>) -> bool {
+        other.eq_normalized(self)
+    }
+}
+
+impl<T: KeyDomain> PartialEq<Key<T>> for Cow<'_, str> {
+    #[inline]
+    fn eq(&self, other: &Key<T>) -> bool {
+        other.eq_normalized(self)
+    }
+}
+
+/// `AsRef` implementation for string conversion
+impl<T: KeyDomain> AsRef<str> for Key<T> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl<T: KeyDomain> AsRef<[u8]> for Key<T> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// From implementation for converting to String
+impl<T: KeyDomain> From<Key<T>> for String {
+    fn from(key: Key<T>) -> Self {
+        key.into_string()
+    }
+}
+
+/// `Add` implementation for appending a string to a key
+///
+/// Note the unusual output type: appending can produce an invalid key, so
+/// `&key + "_suffix"` evaluates to `Result<Key<T>, KeyParseError>` rather
+/// than a key. This delegates to [`Key::concat_str`].
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{Key, KeyDomain};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+/// type TestKey = Key<TestDomain>;
+///
+/// let base = TestKey::new("user")?;
+/// let key = (&base + "_v1")?;
+/// assert_eq!(key.as_str(), "user_v1");
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+impl<T: KeyDomain> Add<&str> for &Key<T> {
+    type Output = Result<Key<T>, KeyParseError>;
+
+    #[inline]
+    fn add(self, suffix: &str) -> Self::Output {
+        self.concat_str(suffix)
+    }
+}
+
+/// `FromStr` implementation for parsing from strings
+impl<T: KeyDomain> FromStr for Key<T> {
+    type Err = KeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Key::new(s)
+    }
+}
+
+/// `TryFrom<&str>` implementation delegating to [`Key::new`]
+impl<T: KeyDomain> TryFrom<&str> for Key<T> {
+    type Error = KeyParseError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Key::new(s)
+    }
+}
+
+/// `TryFrom<String>` implementation delegating to [`Key::from_string`]
+impl<T: KeyDomain> TryFrom<String> for Key<T> {
+    type Error = KeyParseError;
+
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Key::from_string(s)
+    }
+}
+
+/// `TryFrom<&[u8]>` implementation validating UTF-8 before [`Key::new`]
+impl<T: KeyDomain> TryFrom<&[u8]> for Key<T> {
+    type Error = KeyParseError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let s = core::str::from_utf8(bytes).map_err(|e| KeyParseError::InvalidUtf8 {
+            position: e.valid_up_to(),
+        })?;
+        Key::new(s)
+    }
+}
+
+/// `TryFrom<Vec<u8>>` implementation validating UTF-8 before [`Key::from_string`]
+impl<T: KeyDomain> TryFrom<Vec<u8>> for Key<T> {
+    type Error = KeyParseError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        let s = String::from_utf8(bytes).map_err(|e| KeyParseError::InvalidUtf8 {
+            position: e.utf8_error().valid_up_to(),
+        })?;
+        Key::from_string(s)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DefaultDomain;
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    // Test domain
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    struct TestDomain;
+
+    impl KeyDomain for TestDomain {
+        const DOMAIN_NAME: &'static str = "test";
+        const MAX_LENGTH: usize = 32;
+        const HAS_CUSTOM_VALIDATION: bool = true;
+        const HAS_CUSTOM_NORMALIZATION: bool = true;
+
+        fn validate_domain_rules(key: &str) -> Result<(), KeyParseError> {
+            if key.starts_with("invalid_") {
+                return Err(KeyParseError::domain_error(
+                    Self::DOMAIN_NAME,
+                    "Keys cannot start with 'invalid_'",
+                ));
+            }
+            Ok(())
+        }
+
+        fn normalize_domain(key: Cow<'_, str>) -> Cow<'_, str> {
+            if key.contains('-') {
+                Cow::Owned(key.replace('-', "_"))
+            } else {
+                key
+            }
+        }
+
+        fn allowed_characters(c: char) -> bool {
+            c.is_ascii_alphanumeric() || c == '_' || c == '-'
+        }
+
+        fn validation_help() -> Option<&'static str> {
+            Some("Use alphanumeric characters, underscores, and hyphens. Cannot start with 'invalid_'.")
+        }
+    }
+
+    type TestKey = Key<TestDomain>;
+
+    #[test]
+    fn test_key_creation() {
+        let key = TestKey::new("valid_key").unwrap();
+        assert_eq!(key.as_str(), "valid_key");
+        assert_eq!(key.domain(), "test");
+        assert_eq!(key.len(), 9);
+    }
+
+    #[test]
+    fn test_key_normalization() {
+        let key = TestKey::new("Test-Key").unwrap();
+        assert_eq!(key.as_str(), "test_key");
+    }
+
+    #[test]
+    fn test_new_checked() {
+        let (key, changed) = TestKey::new_checked("  Test-Key ").unwrap();
+        assert_eq!(key.as_str(), "test_key");
+        assert!(changed);
+
+        let (key, changed) = TestKey::new_checked("test_key").unwrap();
+        assert_eq!(key.as_str(), "test_key");
+        assert!(!changed);
+
+        assert!(TestKey::new_checked("").is_err());
+    }
+
+    #[test]
+    fn test_new_bounded() {
+        assert!(TestKey::new_bounded("abcdefgh", 8).is_ok());
+        assert!(matches!(
+            TestKey::new_bounded("abcdefghi", 8),
+            Err(KeyParseError::TooLong {
+                max_length: 8,
+                actual_length: 9
+            })
+        ));
+
+        // The override can only tighten the domain limit
+        let long_key = "a".repeat(40);
+        assert!(matches!(
+            TestKey::new_bounded(&long_key, 100),
+            Err(KeyParseError::TooLong {
+                max_length: 32,
+                actual_length: 40
+            })
+        ));
+
+        // Normal pipeline still applies
+        assert!(TestKey::new_bounded("invalid_key", 16).is_err());
+    }
+
+    #[test]
+    fn test_domain_validation() {
+        let result = TestKey::new("invalid_key");
+        assert!(result.is_err());
+
+        if let Err(KeyParseError::DomainValidation { domain, message }) = result {
+            assert_eq!(domain, "test");
+            assert!(message.contains("invalid_"));
+        } else {
+            panic!("Expected domain validation error");
+        }
+    }
+
+    #[test]
+    fn test_post_validate_hook() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct HashGuardDomain;
+
+        impl KeyDomain for HashGuardDomain {
+            const DOMAIN_NAME: &'static str = "hash_guard";
+
+            fn post_validate(key: &str, hash: u64) -> Result<(), KeyParseError> {
+                assert_eq!(hash, Key::<Self>::compute_hash(key));
+                if hash == Key::<Self>::compute_hash("blocked") {
+                    return Err(KeyParseError::domain_error_generic("hash is blocked"));
+                }
+                Ok(())
+            }
+        }
+
+        type GuardKey = Key<HashGuardDomain>;
+
+        assert!(GuardKey::new("allowed").is_ok());
+        assert!(matches!(
+            GuardKey::new("Blocked"),
+            Err(KeyParseError::DomainValidation {
+                domain: "hash_guard",
+                ..
+            })
+        ));
+        assert!(GuardKey::from_string("blocked".to_string()).is_err());
+        assert!(GuardKey::new("block").unwrap().ensure_suffix("ed").is_err());
+        assert!(!crate::validation::is_valid_key::<HashGuardDomain>(
+            "blocked"
+        ));
+    }
+
+    #[test]
+    fn test_common_validation() {
+        // Empty key
+        assert!(matches!(TestKey::new(""), Err(KeyParseError::Empty)));
+
+        // Too long key
+        let long_key = "a".repeat(50);
+        assert!(matches!(
+            TestKey::new(&long_key),
+            Err(KeyParseError::TooLong {
+                max_length: 32,
+                actual_length: 50
+            })
+        ));
+
+        // Invalid character
+        let result = TestKey::new("key with spaces");
+        assert!(matches!(
+            result,
+            Err(KeyParseError::InvalidCharacter {
+                character: ' ',
+                position: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_hash_caching() {
+        let key1 = TestKey::new("test_key").unwrap();
+        let key2 = TestKey::new("test_key").unwrap();
+
+        // Same keys should have same hash
+        assert_eq!(key1.hash(), key2.hash());
+
+        let key3 = TestKey::new("different_key").unwrap();
+        // Different keys should have different hashes (with high probability)
+        assert_ne!(key1.hash(), key3.hash());
+    }
+
+    #[test]
+    fn test_key_methods() {
+        let key = TestKey::new("test_key_example").unwrap();
+        assert!(key.starts_with("test_"));
+        assert!(key.ends_with("_example"));
+        assert!(key.contains("_key_"));
+        assert_eq!(key.len(), 16);
+        assert!(!key.is_empty());
+    }
+
+    #[test]
+    fn test_from_string() {
+        let key = TestKey::from_string("test_key".to_string()).unwrap();
+        assert_eq!(key.as_str(), "test_key");
+    }
+
+    #[test]
+    fn test_try_from_static() {
+        let key = TestKey::try_from_static("static_key").unwrap();
+        assert_eq!(key.as_str(), "static_key");
+
+        let invalid = TestKey::try_from_static("");
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_validation_info() {
+        let key = TestKey::new("test_key").unwrap();
+        let info = key.validation_info();
+
+        assert_eq!(info.domain, "test");
+        assert_eq!(info.max_length, 32);
+        assert_eq!(info.length, 8);
+        assert!(info.has_custom_validation);
+        assert!(info.has_custom_normalization);
+    }
+
+    #[test]
+    fn test_domain_info() {
+        let key = TestKey::new("test_key").unwrap();
+        let info = key.domain_info();
+
+        assert_eq!(info, crate::domain::domain_info::<TestDomain>());
+        assert_eq!(info.name, "test");
+        assert_eq!(info.max_length, 32);
+        assert_eq!(info.schema_version, key.schema_version());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let key = TestKey::new("test_key").unwrap();
+
+        // Test JSON serialization
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(json, r#""test_key""#);
+
+        // Test JSON deserialization
+        let deserialized: TestKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, key);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_and_text_paths_agree() {
+        use serde::de::value::{Error as ValueError, StrDeserializer};
+        use serde::de::{IntoDeserializer, Visitor};
+
+        /// Minimal non-human-readable format yielding one owned string
+        struct BinaryString(String);
+
+        impl<'de> serde::Deserializer<'de> for BinaryString {
+            type Error = ValueError;
+
+            fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+                visitor.visit_string(self.0)
+            }
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+                bytes byte_buf option unit unit_struct newtype_struct seq tuple
+                tuple_struct map struct enum identifier ignored_any
+            }
         }
 
-        // Validate remaining characters
-        for (pos, c) in chars {
-            let char_allowed = is_ascii_allowed_fast(c) || D::allowed_characters(c);
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct CaseSensitiveDomain;
+        impl KeyDomain for CaseSensitiveDomain {
+            const DOMAIN_NAME: &'static str = "case_sensitive";
+            const CASE_INSENSITIVE: bool = false;
+        }
 
-            if !char_allowed {
-                return Err(KeyParseError::InvalidCharacter {
-                    character: c,
-                    position: pos,
-                    expected: Some("allowed by domain"),
-                });
+        fn round_trip<T: KeyDomain>(input: &str) {
+            let key = Key::<T>::new(input).unwrap();
+            let text: StrDeserializer<'_, ValueError> = key.as_str().into_deserializer();
+            let from_text = Key::<T>::deserialize(text).unwrap();
+            let from_binary = Key::<T>::deserialize(BinaryString(key.as_str().into())).unwrap();
+            assert_eq!(from_text, key);
+            assert_eq!(from_binary, key);
+            assert_eq!(from_binary.hash(), key.hash());
+        }
+
+        round_trip::<CaseSensitiveDomain>("Mixed_Case");
+        round_trip::<DefaultDomain>("Mixed_Case");
+        assert_eq!(
+            Key::<CaseSensitiveDomain>::deserialize(BinaryString("Mixed_Case".into()))
+                .unwrap()
+                .as_str(),
+            "Mixed_Case"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut map = HashMap::new();
+        map.insert(TestKey::new("first_key").unwrap(), 1);
+        map.insert(TestKey::new("second_key").unwrap(), 2);
+
+        let json = serde_json::to_string(&map).unwrap();
+        let back: HashMap<TestKey, i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, map);
+
+        let ordered: BTreeMap<TestKey, i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(ordered.len(), 2);
+
+        // Map keys are validated like any other key
+        let err = serde_json::from_str::<HashMap<TestKey, i32>>(r#"{"bad key": 1}"#).unwrap_err();
+        assert!(err.to_string().contains("Invalid character"));
+        let err =
+            serde_json::from_str::<HashMap<TestKey, i32>>(r#"{"invalid_key": 1}"#).unwrap_err();
+        assert!(err.to_string().contains("validation failed"));
+
+        // Escaped and owned input cannot be borrowed from the source
+        let escaped: TestKey = serde_json::from_str(r#""esc\u0061ped""#).unwrap();
+        assert_eq!(escaped.as_str(), "escaped");
+        let value = serde_json::json!({ "from_value": 3 });
+        let from_value: HashMap<TestKey, i32> = serde_json::from_value(value).unwrap();
+        assert_eq!(from_value[&TestKey::new("from_value").unwrap()], 3);
+    }
+
+    #[test]
+    fn test_from_parts() {
+        let key = TestKey::from_parts(&["user", "123", "profile"], "_").unwrap();
+        assert_eq!(key.as_str(), "user_123_profile");
+
+        let parts: Vec<&str> = key.split('_').collect();
+        assert_eq!(parts, vec!["user", "123", "profile"]);
+    }
+
+    #[test]
+    fn test_from_parts_length_precheck() {
+        let part = "a".repeat(10);
+        let parts = [part.as_str(); 3];
+        assert!(TestKey::from_parts(&parts, "_").is_ok());
+
+        let parts = [part.as_str(); 4];
+        assert!(matches!(
+            TestKey::from_parts(&parts, "_"),
+            Err(KeyParseError::TooLong {
+                max_length: 32,
+                actual_length: 43
+            })
+        ));
+
+        // Surrounding whitespace is trimmed and does not count
+        let padded = format!("   {part}");
+        assert!(TestKey::from_parts(&[&padded, &part, &part], "_").is_ok());
+
+        assert!(matches!(
+            TestKey::from_parts(&["a", "b"], "!"),
+            Err(KeyParseError::InvalidStructure {
+                reason: "delimiter not allowed for domain"
+            })
+        ));
+        assert_eq!(
+            TestKey::from_parts_default(&["user", "123"])
+                .unwrap()
+                .as_str(),
+            "user_123"
+        );
+    }
+
+    #[test]
+    fn test_ensure_prefix_suffix() {
+        let key = TestKey::new("profile").unwrap();
+
+        let prefixed = key.ensure_prefix("user_").unwrap();
+        assert_eq!(prefixed.as_str(), "user_profile");
+
+        // Already has prefix
+        let same = prefixed.ensure_prefix("user_").unwrap();
+        assert_eq!(same.as_str(), "user_profile");
+
+        let suffixed = key.ensure_suffix("_v1").unwrap();
+        assert_eq!(suffixed.as_str(), "profile_v1");
+
+        // Already has suffix
+        let same = suffixed.ensure_suffix("_v1").unwrap();
+        assert_eq!(same.as_str(), "profile_v1");
+    }
+
+    #[test]
+    fn test_concat_str_and_add() {
+        let key = TestKey::new("user").unwrap();
+
+        assert_eq!(key.concat_str("_v1").unwrap().as_str(), "user_v1");
+        assert_eq!((&key + "_v1").unwrap().as_str(), "user_v1");
+
+        // Always appends, unlike ensure_suffix
+        let twice = key.concat_str("_v1").unwrap().concat_str("_v1").unwrap();
+        assert_eq!(twice.as_str(), "user_v1_v1");
+
+        assert!((&key + " bad").is_err());
+        assert!(key.concat_str(&"a".repeat(40)).is_err());
+    }
+
+    #[test]
+    fn test_try_as_array() {
+        let key = TestKey::new("0123456789abcdef").unwrap();
+        let bytes: [u8; 16] = key.try_as_array().unwrap();
+        assert_eq!(&bytes, key.as_str().as_bytes());
+        assert!(key.try_as_array::<15>().is_none());
+        assert!(key.try_as_array::<17>().is_none());
+    }
+
+    #[test]
+    fn test_display_truncated() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct UnicodeDomain;
+
+        impl KeyDomain for UnicodeDomain {
+            const DOMAIN_NAME: &'static str = "unicode";
+
+            fn allowed_characters(c: char) -> bool {
+                c.is_alphanumeric()
             }
+        }
 
-            if let Some(prev) = prev_char {
-                if !D::allowed_consecutive_characters(prev, c) {
-                    return Err(KeyParseError::InvalidStructure {
-                        reason: "consecutive characters not allowed",
-                    });
-                }
+        let key = TestKey::new("abcdef").unwrap();
+        assert_eq!(key.display_truncated(6).to_string(), "abcdef");
+        assert_eq!(key.display_truncated(4).to_string(), "abcd…(2 more)");
+        assert_eq!(key.display_truncated(0).to_string(), "…(6 more)");
+
+        let unicode = Key::<UnicodeDomain>::new("héllo").unwrap();
+        assert_eq!(unicode.display_truncated(2).to_string(), "hé…(3 more)");
+    }
+
+    #[test]
+    fn test_is_prefix_free() {
+        let keys = |items: &[&str]| -> Vec<TestKey> {
+            items.iter().map(|s| TestKey::new(*s).unwrap()).collect()
+        };
+
+        assert!(TestKey::is_prefix_free(&[]).is_ok());
+        assert!(TestKey::is_prefix_free(&keys(&["a_b", "a_c", "ab"])).is_ok());
+
+        // Byte order would place "a_b-x" between "a_b" and "a_b_c"
+        assert_eq!(
+            TestKey::is_prefix_free(&keys(&["a_b_c", "a_b-x", "a_b"])),
+            Err((2, 0))
+        );
+        assert_eq!(
+            TestKey::is_prefix_free(&keys(&["x", "y", "x"])),
+            Err((0, 2))
+        );
+    }
+
+    #[test]
+    fn test_from_cow() {
+        let borrowed = TestKey::from_cow(Cow::Borrowed("cow_key")).unwrap();
+        let owned = TestKey::from_cow(Cow::Owned("cow_key".to_string())).unwrap();
+        assert_eq!(borrowed, owned);
+        assert_eq!(borrowed.hash(), owned.hash());
+
+        assert!(TestKey::from_cow(Cow::Borrowed("")).is_err());
+        assert!(TestKey::from_cow(Cow::Owned("bad key".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_split_inclusive() {
+        let key = TestKey::new("a_b_c").unwrap();
+        let parts: Vec<&str> = key.split_inclusive('_').collect();
+        assert_eq!(parts, vec!["a_", "b_", "c"]);
+        assert_eq!(parts.concat(), key.as_str());
+
+        let parts: Vec<&str> = key.split_inclusive('-').collect();
+        assert_eq!(parts, vec!["a_b_c"]);
+    }
+
+    #[test]
+    fn test_to_owned_segments() {
+        let key = TestKey::new("a_b_c").unwrap();
+        assert_eq!(key.to_owned_segments(), vec!["a", "b", "c"]);
+
+        let path = Key::<crate::PathDomain>::new("users/profile").unwrap();
+        assert_eq!(path.to_owned_segments(), vec!["users", "profile"]);
+    }
+
+    #[test]
+    fn test_concat_keys() {
+        type IdKey = Key<crate::IdentifierDomain>;
+
+        let alpha = TestKey::new("alpha").unwrap();
+        let beta = TestKey::new("beta").unwrap();
+        let gamma = TestKey::new("gamma").unwrap();
+
+        let joined = TestKey::concat_keys(&[&alpha, &beta, &gamma], '_').unwrap();
+        assert_eq!(joined.as_str(), "alpha_beta_gamma");
+        assert_eq!(
+            joined,
+            TestKey::from_parts(&["alpha", "beta", "gamma"], "_").unwrap()
+        );
+        assert_eq!(TestKey::concat_keys(&[&alpha], '_').unwrap(), alpha);
+
+        assert!(matches!(
+            TestKey::concat_keys(&[], '_'),
+            Err(KeyParseError::Empty)
+        ));
+        assert!(matches!(
+            TestKey::concat_keys(&[&alpha, &beta], '!'),
+            Err(KeyParseError::InvalidCharacter {
+                character: '!',
+                position: 5,
+                ..
+            })
+        ));
+
+        let long = TestKey::new("a".repeat(20)).unwrap();
+        assert!(matches!(
+            TestKey::concat_keys(&[&long, &long], '_'),
+            Err(KeyParseError::TooLong {
+                actual_length: 41,
+                ..
+            })
+        ));
+
+        let left = IdKey::new("left").unwrap();
+        let right = IdKey::new("right").unwrap();
+        assert!(IdKey::concat_keys(&[&left, &right], '-').is_err());
+    }
+
+    #[test]
+    fn test_concat_keys_lowercases_separator() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct LowerDomain;
+        impl KeyDomain for LowerDomain {
+            const DOMAIN_NAME: &'static str = "lower";
+            const CASE_INSENSITIVE: bool = true;
+        }
+        type LowerKey = Key<LowerDomain>;
+
+        let a = LowerKey::new("a").unwrap();
+        let b = LowerKey::new("b").unwrap();
+        let joined = LowerKey::concat_keys(&[&a, &b], 'X').unwrap();
+        assert_eq!(joined.as_str(), "axb");
+        assert_eq!(joined, LowerKey::new("aXb").unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_compact_key() {
+        let key = TestKey::new("compact_key").unwrap();
+        let compact = CompactKey::from(key.clone());
+
+        let json = serde_json::to_string(&compact).unwrap();
+        assert_eq!(json, r#""compact_key""#);
+        let back: CompactKey<TestDomain> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.into_inner(), key);
+
+        let rebuilt =
+            TestKey::from_compact_parts("compact_key".to_string(), key.hash(), 11).unwrap();
+        assert_eq!(rebuilt, key);
+        assert_eq!(rebuilt.hash(), key.hash());
+
+        assert!(TestKey::from_compact_parts("compact_key".to_string(), key.hash(), 3).is_err());
+
+        // A stale stored hash is not trusted
+        let stale =
+            TestKey::from_compact_parts("compact_key".to_string(), key.hash() ^ 1, 11).unwrap();
+        assert_eq!(stale.hash(), key.hash());
+    }
+
+    #[test]
+    fn test_ascii_only_fast_path() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct AsciiDomain;
+
+        impl KeyDomain for AsciiDomain {
+            const DOMAIN_NAME: &'static str = "ascii";
+            const ASCII_ONLY: bool = true;
+
+            fn allowed_characters(c: char) -> bool {
+                c.is_alphanumeric() || c == '_'
             }
-            prev_char = Some(c);
+
+            fn allowed_consecutive_characters(prev: char, curr: char) -> bool {
+                !(prev == '_' && curr == '_')
+            }
+        }
+
+        type AsciiKey = Key<AsciiDomain>;
+
+        assert!(AsciiKey::new("plain_ascii_key").is_ok());
+        assert!(matches!(
+            AsciiKey::new("caf\u{e9}"),
+            Err(KeyParseError::InvalidCharacter {
+                character: '\u{e9}',
+                position: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            AsciiKey::new("a-b"),
+            Err(KeyParseError::InvalidCharacter { character: '-', .. })
+        ));
+        assert!(matches!(
+            AsciiKey::new("a__b"),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+        assert!(matches!(
+            AsciiKey::new("ab_"),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+    }
+
+    #[test]
+    fn test_first_and_last_char() {
+        let key = TestKey::new("a").unwrap();
+        assert_eq!(key.first_char(), 'a');
+        assert_eq!(key.last_char(), 'a');
+
+        let key = TestKey::new("start_end9").unwrap();
+        assert_eq!(key.first_char(), 's');
+        assert_eq!(key.last_char(), '9');
+    }
+
+    #[test]
+    fn test_redacted() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct SecretDomain;
+
+        impl KeyDomain for SecretDomain {
+            const DOMAIN_NAME: &'static str = "secret";
+            const REDACT_IN_DISPLAY: bool = true;
         }
 
-        // Check last character
-        if let Some(last) = prev_char {
-            if !D::allowed_end_character(last) {
-                return Err(KeyParseError::InvalidStructure {
-                    reason: "invalid end character",
-                });
+        let key = TestKey::new("visible_key").unwrap();
+        let redacted = key.redacted().to_string();
+        assert_eq!(redacted, format!("test:#{:04x}…", key.hash() >> 48));
+        assert_eq!(redacted, key.redacted().to_string());
+        assert_eq!(key.to_string(), "test:visible_key");
+
+        let secret = Key::<SecretDomain>::new("alice").unwrap();
+        assert_eq!(secret.to_string(), secret.redacted().to_string());
+        assert!(!secret.to_string().contains("alice"));
+        assert_eq!(secret.reveal(), "alice");
+
+        let debug = format!("{secret:?}");
+        assert_eq!(debug, format!("Key({})", secret.redacted()));
+        assert!(!format!("{:?}", RehashKey::new(secret.clone())).contains("alice"));
+        assert!(format!("{key:?}").contains("visible_key"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_on_create_hook() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct CountingDomain;
+
+        impl KeyDomain for CountingDomain {
+            const DOMAIN_NAME: &'static str = "counting";
+
+            fn on_create(_key: &str) {
+                CREATED.fetch_add(1, Ordering::Relaxed);
             }
         }
 
-        Ok(())
+        type CountingKey = Key<CountingDomain>;
+
+        let _ = CountingKey::new("first").unwrap();
+        let _ = CountingKey::from_string("second".to_string()).unwrap();
+        assert!(CountingKey::new("not valid").is_err());
+        let _ = CountingKey::new("third")
+            .unwrap()
+            .ensure_suffix("_x")
+            .unwrap();
+        assert_eq!(CREATED.load(Ordering::Relaxed), 4);
+
+        // Domains keeping the default hook never reach an observer
+        let _ = TestKey::new("fourth").unwrap();
+        let _ = TestKey::new("fifth").unwrap().ensure_suffix("_x").unwrap();
+        <TestDomain as KeyDomain>::on_create("sixth");
+        assert_eq!(CREATED.load(Ordering::Relaxed), 4);
+
+        // ...and overriding it adds no per-key state
+        assert_eq!(
+            core::mem::size_of::<CountingKey>(),
+            core::mem::size_of::<TestKey>()
+        );
     }
 
-    /// Normalize a borrowed string
-    pub(crate) fn normalize<D: KeyDomain>(key: &str) -> Cow<'_, str> {
-        let trimmed = key.trim();
+    #[test]
+    fn test_write_to_and_read_from() {
+        let first = TestKey::new("first").unwrap();
+        let second = TestKey::new("second_key").unwrap();
 
-        let needs_lowercase =
-            D::CASE_INSENSITIVE && trimmed.chars().any(|c| c.is_ascii_uppercase());
+        let mut buf = [0u8; 64];
+        let n1 = first.write_to(&mut buf).unwrap();
+        let n2 = second.write_to(&mut buf[n1..]).unwrap();
+        assert_eq!(n1, 9);
+        assert_eq!(&buf[..4], &5u32.to_le_bytes());
 
-        let lowercased = if needs_lowercase {
-            Cow::Owned(trimmed.to_ascii_lowercase())
-        } else if trimmed.len() != key.len() {
-            // Only trimming was needed
-            Cow::Owned(trimmed.to_string())
-        } else {
-            // No changes needed
-            Cow::Borrowed(trimmed)
-        };
+        let (decoded, read) = TestKey::read_from(&buf).unwrap();
+        assert_eq!((decoded, read), (first.clone(), n1));
+        let (decoded, read) = TestKey::read_from(&buf[n1..]).unwrap();
+        assert_eq!((decoded, read), (second, n2));
 
-        // Apply domain-specific normalization
-        D::normalize_domain(lowercased)
-    }
+        let mut small = [0xffu8; 8];
+        assert!(first.write_to(&mut small).is_none());
+        assert_eq!(small, [0xff; 8]);
 
-    /// Normalize an owned string efficiently
-    fn normalize_owned<D: KeyDomain>(mut key: String) -> String {
-        // In-place operations when possible
-        let trimmed = key.trim();
-        if trimmed.len() != key.len() {
-            key = trimmed.to_string();
-        }
+        assert!(matches!(
+            TestKey::read_from(&[1, 0]),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+        assert!(matches!(
+            TestKey::read_from(&buf[..n1 - 1]),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+        assert!(matches!(
+            TestKey::read_from(&[255, 255, 255, 255]),
+            Err(KeyParseError::TooLong { .. })
+        ));
+        assert!(matches!(
+            TestKey::read_from(&[2, 0, 0, 0, 0xc3, 0x28]),
+            Err(KeyParseError::InvalidUtf8 { position: 0 })
+        ));
+        assert!(TestKey::read_from(&[3, 0, 0, 0, b'a', b' ', b'b']).is_err());
+    }
 
-        key.make_ascii_lowercase();
+    #[test]
+    fn test_matches_any_prefix() {
+        let key = TestKey::new("user_profile").unwrap();
+        assert_eq!(key.matches_any_prefix(&[]), None);
+        assert_eq!(key.matches_any_prefix(&["usr", "user", "user_"]), Some(1));
+        assert_eq!(key.matches_any_prefix(&["", "user"]), Some(0));
+        assert_eq!(key.matches_any_prefix(&["user_profile_x"]), None);
+    }
 
-        // Apply domain normalization
-        match D::normalize_domain(Cow::Owned(key)) {
-            Cow::Owned(s) => s,
-            Cow::Borrowed(_) => unreachable!("We passed Cow::Owned"),
-        }
+    #[test]
+    fn test_string_comparisons() {
+        let owned = String::from("user-name");
+        let short = String::from("user");
+        let key = TestKey::new("user-name").unwrap();
+        assert_eq!(key.as_str(), "user_name");
+
+        assert!(key == "user_name");
+        assert!(key == *"user_name");
+        assert!(key == owned);
+        assert!(key == Cow::Borrowed(" USER-name "));
+        assert!(key != "other");
+
+        assert!("user_name" == key);
+        assert!(*"USER_NAME" == key);
+        assert!(owned == key);
+        assert!(Cow::<str>::Owned("user_name".to_string()) == key);
+        assert!(short != key);
+
+        let id = Key::<crate::IdentifierDomain>::new("UserName").unwrap();
+        assert!(id == "UserName");
+        assert!(id != "username");
     }
 
-    /// Compute hash using the configured algorithm
-    ///
-    /// The hash algorithm is selected at compile time based on feature flags,
-    /// allowing for different performance/security trade-offs.
-    pub(crate) fn compute_hash(key: &str) -> u64 {
-        if key.is_empty() {
-            return 0;
+    #[test]
+    fn test_domain_default() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct SentinelDomain;
+        impl KeyDomain for SentinelDomain {
+            const DOMAIN_NAME: &'static str = "sentinel";
+
+            fn default_key() -> Option<&'static str> {
+                Some("None_Yet")
+            }
         }
 
-        // Priority-based selection: fast > secure > crypto > default
-        // Use early returns to avoid unreachable code warnings
+        let key = Key::<SentinelDomain>::domain_default().unwrap();
+        assert_eq!(key.as_str(), "none_yet");
+        assert!(TestKey::domain_default().is_none());
+    }
 
-        // 1. Fast feature: GxHash with AHash fallback
-        #[cfg(feature = "fast")]
-        {
-            // Try GxHash on supported platforms
-            #[cfg(any(
-                all(target_arch = "x86_64", target_feature = "aes"),
-                all(
-                    target_arch = "aarch64",
-                    target_feature = "aes",
-                    target_feature = "neon"
-                )
-            ))]
-            {
-                gxhash::gxhash64(key.as_bytes(), 0)
+    #[test]
+    fn test_escape_for() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct LooseDomain;
+        impl KeyDomain for LooseDomain {
+            const DOMAIN_NAME: &'static str = "loose";
+            const CASE_INSENSITIVE: bool = false;
+            fn allowed_characters(c: char) -> bool {
+                !c.is_control()
             }
-
-            // Fallback to AHash when GxHash requirements not met
-            #[cfg(not(any(
-                all(target_arch = "x86_64", target_feature = "aes"),
-                all(
-                    target_arch = "aarch64",
-                    target_feature = "aes",
-                    target_feature = "neon"
-                )
-            )))]
-            {
-                use core::hash::Hasher;
-                let mut hasher = ahash::AHasher::default();
-                hasher.write(key.as_bytes());
-                return hasher.finish();
+            fn allowed_consecutive_characters(_prev: char, _curr: char) -> bool {
+                true
             }
         }
-
-        // 2. Secure feature: AHash for DoS resistance
-        #[cfg(all(feature = "secure", not(feature = "fast")))]
-        {
-            use core::hash::Hasher;
-            let mut hasher = ahash::AHasher::default();
-            hasher.write(key.as_bytes());
-            return hasher.finish();
+        type LooseKey = Key<LooseDomain>;
+
+        let plain = TestKey::new("user_name").unwrap();
+        for ctx in [
+            EscapeContext::SqlIdentifier,
+            EscapeContext::Shell,
+            EscapeContext::LogField,
+        ] {
+            assert!(matches!(plain.escape_for(ctx), Cow::Borrowed("user_name")));
         }
 
-        // 3. Crypto feature: Blake3 for cryptographic security
-        #[cfg(all(feature = "crypto", not(any(feature = "fast", feature = "secure"))))]
-        {
-            let hash = blake3::hash(key.as_bytes());
-            let bytes = hash.as_bytes();
-            return u64::from_le_bytes([
-                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-            ]);
-        }
+        let upper = LooseKey::new("Users").unwrap();
+        assert_eq!(upper.escape_for(EscapeContext::SqlIdentifier), "\"Users\"");
+        let digit = TestKey::new("1st").unwrap();
+        assert_eq!(digit.escape_for(EscapeContext::SqlIdentifier), "\"1st\"");
+
+        let tricky = LooseKey::new("a \"b\" c=d's").unwrap();
+        assert_eq!(
+            tricky.escape_for(EscapeContext::SqlIdentifier),
+            "\"a \"\"b\"\" c=d's\""
+        );
+        assert_eq!(
+            tricky.escape_for(EscapeContext::Shell),
+            "'a \"b\" c=d'\\''s'"
+        );
+        assert_eq!(
+            tricky.escape_for(EscapeContext::LogField),
+            "\"a \\\"b\\\" c=d's\""
+        );
+    }
 
-        // 4. Default: Standard library hasher or FNV-1a
-        #[cfg(not(any(feature = "fast", feature = "secure", feature = "crypto")))]
-        {
-            #[cfg(feature = "std")]
-            {
-                use core::hash::Hasher;
-                use std::collections::hash_map::DefaultHasher;
-                let mut hasher = DefaultHasher::new();
-                hasher.write(key.as_bytes());
-                return hasher.finish();
-            }
+    #[test]
+    fn test_convert_to() {
+        let key = Key::<DefaultDomain>::new("Some_Key").unwrap();
+        let converted: TestKey = key.convert_to().unwrap();
+        let fresh = TestKey::new("some_key").unwrap();
+        assert_eq!(converted, fresh);
+        assert_eq!(converted.hash(), fresh.hash());
+        assert_eq!(converted.len(), fresh.len());
+
+        let rejected = Key::<DefaultDomain>::new("invalid_key").unwrap();
+        assert!(matches!(
+            rejected.convert_to::<TestDomain>(),
+            Err(KeyParseError::DomainValidation { .. })
+        ));
+    }
 
-            #[cfg(not(feature = "std"))]
-            {
-                return Self::fnv1a_hash(key.as_bytes());
+    #[test]
+    fn test_widen_and_try_into_domain() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct NarrowDomain;
+        impl KeyDomain for NarrowDomain {
+            const DOMAIN_NAME: &'static str = "narrow";
+            const MAX_LENGTH: usize = 16;
+            fn allowed_characters(c: char) -> bool {
+                c.is_ascii_lowercase() || c == '_'
             }
         }
+        impl SubdomainOf<DefaultDomain> for NarrowDomain {}
+
+        let narrow = Key::<NarrowDomain>::new("some_key").unwrap();
+        let hash = narrow.hash();
+        let wide: Key<DefaultDomain> = narrow.widen();
+        assert_eq!(wide.as_str(), "some_key");
+        assert_eq!(wide.hash(), hash);
+        assert_eq!(wide, Key::<DefaultDomain>::new("some_key").unwrap());
+
+        let back = wide.try_into_domain::<NarrowDomain>().unwrap();
+        assert_eq!(back.as_str(), "some_key");
+        let dotted = Key::<DefaultDomain>::new("a.b").unwrap();
+        assert!(dotted.try_into_domain::<NarrowDomain>().is_err());
     }
 
-    /// FNV-1a hash implementation for `no_std` environments
-    #[allow(dead_code)]
-    fn fnv1a_hash(bytes: &[u8]) -> u64 {
-        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
-        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+    #[test]
+    fn test_idempotent_paths_skip_rehash() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct CountingDomain;
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+        impl KeyDomain for CountingDomain {
+            const DOMAIN_NAME: &'static str = "counting";
+            fn on_create(_key: &str) {
+                CREATED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        type CountingKey = Key<CountingDomain>;
+
+        let key = CountingKey::new("user_profile").unwrap();
+        let created = CREATED.load(Ordering::Relaxed);
+
+        assert_eq!(key.ensure_prefix("user_").unwrap(), key);
+        assert_eq!(key.ensure_suffix("_profile").unwrap(), key);
+        assert_eq!(key.to_case(CaseStyle::Snake).unwrap(), key);
+        assert_eq!(key.map_segments(str::to_string).unwrap(), key);
+        assert_eq!(
+            CountingKey::from_parts_trusted("user_profile", &key).unwrap(),
+            key
+        );
+        assert_eq!(CREATED.load(Ordering::Relaxed), created);
+
+        let other = CountingKey::from_parts_trusted("user_settings", &key).unwrap();
+        assert_eq!(other.as_str(), "user_settings");
+        assert_eq!(CREATED.load(Ordering::Relaxed), created + 1);
+    }
 
-        let mut hash = FNV_OFFSET_BASIS;
-        for &byte in bytes {
-            hash ^= u64::from(byte);
-            hash = hash.wrapping_mul(FNV_PRIME);
+    #[test]
+    fn test_char_count() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct WideDomain;
+        impl KeyDomain for WideDomain {
+            const DOMAIN_NAME: &'static str = "wide";
+            fn allowed_characters(c: char) -> bool {
+                c.is_alphanumeric()
+            }
         }
-        hash
+
+        let key = Key::<WideDomain>::new("héllo").unwrap();
+        assert_eq!(key.len(), 6);
+        assert_eq!(key.char_count(), 5);
+
+        let ascii = TestKey::new("hello").unwrap();
+        assert_eq!(ascii.char_count(), ascii.len());
     }
-}
 
-// ============================================================================
-// SUPPORTING TYPES
-// ============================================================================
+    #[test]
+    fn test_len_utf16() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct WideDomain;
+        impl KeyDomain for WideDomain {
+            const DOMAIN_NAME: &'static str = "wide";
+            fn allowed_characters(c: char) -> bool {
+                !c.is_whitespace() && !c.is_control()
+            }
+        }
 
-/// Information about a key's validation characteristics
-///
-/// This structure provides detailed information about how a key was validated
-/// and what domain-specific features are enabled.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct KeyValidationInfo {
-    /// Domain name
-    pub domain: &'static str,
-    /// Maximum allowed length for this domain
-    pub max_length: usize,
-    /// Actual length of the key
-    pub length: usize,
-    /// Whether the domain has custom validation rules
-    pub has_custom_validation: bool,
-    /// Whether the domain has custom normalization rules
-    pub has_custom_normalization: bool,
-}
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct AsciiDomain;
+        impl KeyDomain for AsciiDomain {
+            const DOMAIN_NAME: &'static str = "ascii";
+            const ASCII_ONLY: bool = true;
+        }
 
-// ============================================================================
-// STANDARD TRAIT IMPLEMENTATIONS
-// ============================================================================
+        let emoji = Key::<WideDomain>::new("a\u{1F600}b").unwrap();
+        assert_eq!(emoji.len(), 6);
+        assert_eq!(emoji.len_utf16(), 4);
 
-/// Display implementation shows domain and key
-impl<T: KeyDomain> fmt::Display for Key<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", T::DOMAIN_NAME, self.inner)
+        let ascii = Key::<AsciiDomain>::new("plain_key").unwrap();
+        assert_eq!(ascii.len_utf16(), ascii.len());
+        assert_eq!(TestKey::new("plain").unwrap().len_utf16(), 5);
     }
-}
 
-/// `AsRef` implementation for string conversion
-impl<T: KeyDomain> AsRef<str> for Key<T> {
-    #[inline]
-    fn as_ref(&self) -> &str {
-        &self.inner
+    #[test]
+    fn test_hash_hex() {
+        let key = TestKey::new("example").unwrap();
+        let hex = key.hash_hex();
+        assert_eq!(hex.len(), 16);
+        assert!(hex
+            .chars()
+            .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+        assert_eq!(u64::from_str_radix(&hex, 16).unwrap(), key.hash());
+
+        let mut written = String::new();
+        key.hash_hex_into(&mut written).unwrap();
+        assert_eq!(written, hex);
     }
-}
 
-/// From implementation for converting to String
-impl<T: KeyDomain> From<Key<T>> for String {
-    fn from(key: Key<T>) -> Self {
-        key.inner.into()
+    #[test]
+    fn test_split_numeric_suffix() {
+        let key = TestKey::new("item_0009").unwrap();
+        assert_eq!(key.split_numeric_suffix(), ("item_", Some(9)));
+        assert_eq!(key.numeric_suffix_width(), 4);
+
+        let digits_only = TestKey::new("42").unwrap();
+        assert_eq!(digits_only.split_numeric_suffix(), ("", Some(42)));
+
+        let none = TestKey::new("item_a").unwrap();
+        assert_eq!(none.split_numeric_suffix(), ("item_a", None));
+        assert_eq!(none.numeric_suffix_width(), 0);
+
+        let huge = TestKey::new("n_99999999999999999999").unwrap();
+        assert_eq!(
+            huge.split_numeric_suffix(),
+            ("n_99999999999999999999", None)
+        );
+        assert_eq!(huge.numeric_suffix_width(), 20);
     }
-}
 
-/// `FromStr` implementation for parsing from strings
-impl<T: KeyDomain> FromStr for Key<T> {
-    type Err = KeyParseError;
+    #[test]
+    fn test_increment() {
+        let next = |s: &str| TestKey::new(s).unwrap().increment().unwrap();
+        assert_eq!(next("item_0009").as_str(), "item_0010");
+        assert_eq!(next("item_99").as_str(), "item_100");
+        assert_eq!(next("item_7").as_str(), "item_8");
+        assert_eq!(next("item").as_str(), "item_1");
+
+        let max = TestKey::new(format!("n_{}", u64::MAX)).unwrap();
+        assert!(matches!(
+            max.increment(),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Key::new(s)
+        let full = TestKey::new("a".repeat(32)).unwrap();
+        assert!(matches!(
+            full.increment(),
+            Err(KeyParseError::TooLong { .. })
+        ));
     }
-}
-
-// ============================================================================
-// TESTS
-// ============================================================================
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::DefaultDomain;
-    #[cfg(not(feature = "std"))]
-    use alloc::format;
-    #[cfg(not(feature = "std"))]
-    use alloc::string::ToString;
-    #[cfg(not(feature = "std"))]
-    use alloc::vec;
-    #[cfg(not(feature = "std"))]
-    use alloc::vec::Vec;
+    #[test]
+    fn test_canonicalize_hook() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct EvenDomain;
+        impl KeyDomain for EvenDomain {
+            const DOMAIN_NAME: &'static str = "even";
+            fn canonicalize(input: Cow<'_, str>) -> Result<Cow<'_, str>, KeyParseError> {
+                let input = if input.contains('.') {
+                    Cow::Owned(input.replace('.', "-"))
+                } else {
+                    input
+                };
+                if input.len() % 2 == 0 {
+                    Ok(input)
+                } else {
+                    Err(KeyParseError::domain_error("even", "length must be even"))
+                }
+            }
+        }
+        type EvenKey = Key<EvenDomain>;
+
+        assert_eq!(EvenKey::new("a.bc").unwrap().as_str(), "a-bc");
+        assert_eq!(
+            EvenKey::from_string("A.BC".to_string()).unwrap().as_str(),
+            "a-bc"
+        );
+        assert!(EvenKey::new("abc").is_err());
+        assert!(crate::validation::is_valid_key::<EvenDomain>("abcd"));
+
+        // Assembly paths also consult the hook
+        let key = EvenKey::new("ab").unwrap();
+        assert!(key.ensure_prefix("c").is_err());
+        assert!(key.ensure_prefix("cd").is_ok());
+
+        // ...and store the canonical form, like `new` does
+        let prefixed = key.ensure_prefix("x.").unwrap();
+        assert_eq!(prefixed.as_str(), "x-ab");
+        assert_eq!(prefixed, EvenKey::new("x.ab").unwrap());
+        assert_eq!(key.ensure_suffix(".y").unwrap().as_str(), "ab-y");
+        assert_eq!(
+            EvenKey::from_static_unchecked("X.ab"),
+            EvenKey::new("x.ab").unwrap()
+        );
+        let cd = EvenKey::new("cd").unwrap();
+        let joined = EvenKey::concat_keys(&[&key, &cd, &key], '.').unwrap();
+        assert_eq!(joined.as_str(), "ab-cd-ab");
+        assert_eq!(joined, EvenKey::new("ab.cd.ab").unwrap());
+    }
 
-    // Test domain
-    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-    struct TestDomain;
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "is not a valid even key")]
+    fn test_from_static_unchecked_rejected_literal() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct EvenDomain;
+        impl KeyDomain for EvenDomain {
+            const DOMAIN_NAME: &'static str = "even";
+            fn canonicalize(input: Cow<'_, str>) -> Result<Cow<'_, str>, KeyParseError> {
+                if input.len() % 2 == 0 {
+                    Ok(input)
+                } else {
+                    Err(KeyParseError::domain_error("even", "length must be even"))
+                }
+            }
+        }
 
-    impl KeyDomain for TestDomain {
-        const DOMAIN_NAME: &'static str = "test";
-        const MAX_LENGTH: usize = 32;
-        const HAS_CUSTOM_VALIDATION: bool = true;
-        const HAS_CUSTOM_NORMALIZATION: bool = true;
+        let _ = Key::<EvenDomain>::from_static_unchecked("abc");
+    }
 
-        fn validate_domain_rules(key: &str) -> Result<(), KeyParseError> {
-            if key.starts_with("invalid_") {
-                return Err(KeyParseError::domain_error(
-                    Self::DOMAIN_NAME,
-                    "Keys cannot start with 'invalid_'",
-                ));
+    #[test]
+    fn test_min_length_reports_too_short() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct MinDomain;
+        impl KeyDomain for MinDomain {
+            const DOMAIN_NAME: &'static str = "min";
+            fn min_length() -> usize {
+                4
             }
-            Ok(())
         }
 
-        fn normalize_domain(key: Cow<'_, str>) -> Cow<'_, str> {
-            if key.contains('-') {
-                Cow::Owned(key.replace('-', "_"))
-            } else {
-                key
+        let err = Key::<MinDomain>::new("ab").unwrap_err();
+        assert_eq!(
+            err,
+            KeyParseError::TooShort {
+                min_length: 4,
+                actual_length: 2
             }
-        }
+        );
+        assert_eq!(err.category(), crate::ErrorCategory::Length);
+        assert!(Key::<MinDomain>::new("abcd").is_ok());
+    }
+
+    #[test]
+    fn test_from_string_respects_case_sensitivity() {
+        type IdentKey = Key<crate::domain::IdentifierDomain>;
+
+        let owned = IdentKey::from_string("PublicVar".into()).unwrap();
+        let borrowed = IdentKey::new("PublicVar").unwrap();
+        assert_eq!(owned.as_str(), "PublicVar");
+        assert_eq!(owned, borrowed);
+        assert_eq!(owned.hash(), borrowed.hash());
+
+        // Case-insensitive domains still lowercase on both paths
+        assert_eq!(
+            TestKey::from_string("MixedCase".into()).unwrap(),
+            TestKey::new("MixedCase").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_key_hasher_matches_str() {
+        use core::hash::BuildHasher;
 
-        fn allowed_characters(c: char) -> bool {
-            c.is_ascii_alphanumeric() || c == '_' || c == '-'
+        let build = BuildKeyHasher::default();
+        let key = TestKey::new("alice").unwrap();
+        assert_eq!(build.hash_one(&key), build.hash_one("alice"));
+        assert_eq!(build.hash_one(&key), key.hash());
+
+        #[cfg(feature = "std")]
+        {
+            let mut map: KeyMap<TestDomain, u32> = KeyMap::default();
+            map.insert(key.clone(), 1);
+            map.insert(TestKey::new("bob").unwrap(), 2);
+            assert_eq!(map.get_str("alice"), Some(&1));
+            assert_eq!(map.get_str("bob"), Some(&2));
+            assert_eq!(map.get_str("carol"), None);
+            assert!(map.contains_str("alice"));
+            assert_eq!(map.get(&key), Some(&1));
+            *map.get_str_mut("bob").unwrap() += 1;
+            assert_eq!(map.remove_str("bob"), Some(3));
+            assert_eq!(map.len(), 1);
+
+            // Lookups by content work with any hasher, not only BuildKeyHasher
+            let plain: std::collections::HashMap<TestKey, u32> =
+                map.into_inner().into_iter().collect();
+            let q: &dyn lookup::KeyContent = &"alice";
+            assert_eq!(plain.get(q), Some(&1));
         }
+    }
 
-        fn validation_help() -> Option<&'static str> {
-            Some("Use alphanumeric characters, underscores, and hyphens. Cannot start with 'invalid_'.")
+    #[test]
+    fn test_static_keys_are_normalized() {
+        let unchecked = TestKey::from_static_unchecked("  Static_Key ");
+        let checked = TestKey::new("static_key").unwrap();
+        assert_eq!(unchecked.as_str(), "static_key");
+        assert_eq!(unchecked, checked);
+        assert_eq!(unchecked.hash(), checked.hash());
+        assert_eq!(unchecked.len(), checked.len());
+        assert_eq!(TestKey::try_from_static("Static_Key").unwrap(), checked);
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        fn byte_len(value: impl AsRef<[u8]>) -> usize {
+            value.as_ref().len()
         }
+
+        let key = TestKey::new("User_Key").unwrap();
+        assert_eq!(key.as_bytes(), b"user_key");
+        assert_eq!(key.as_bytes(), key.as_str().as_bytes());
+        assert_eq!(byte_len(&key), 8);
     }
 
-    type TestKey = Key<TestDomain>;
+    #[test]
+    fn test_ascii_case_transforms() {
+        type IdentKey = Key<crate::domain::IdentifierDomain>;
+
+        let key = IdentKey::new("user_Id").unwrap();
+        let upper = key.to_ascii_uppercase().unwrap();
+        let fresh = IdentKey::new("USER_ID").unwrap();
+        assert_eq!(upper, fresh);
+        assert_eq!(upper.hash(), fresh.hash());
+        assert_eq!(upper.len(), fresh.len());
+
+        let lower = key.to_ascii_lowercase().unwrap();
+        assert_eq!(lower.hash(), IdentKey::new("user_id").unwrap().hash());
+
+        // Case-insensitive domains normalize the result straight back
+        let plain = TestKey::new("plain").unwrap();
+        assert_eq!(plain.to_ascii_uppercase().unwrap(), plain);
+    }
 
     #[test]
-    fn test_key_creation() {
-        let key = TestKey::new("valid_key").unwrap();
-        assert_eq!(key.as_str(), "valid_key");
-        assert_eq!(key.domain(), "test");
-        assert_eq!(key.len(), 9);
+    fn test_strip_prefix_and_suffix() {
+        let key = TestKey::new("user_profile").unwrap();
+
+        assert_eq!(
+            key.strip_prefix("user_").unwrap().unwrap().as_str(),
+            "profile"
+        );
+        assert_eq!(
+            key.strip_suffix("_profile").unwrap().unwrap().as_str(),
+            "user"
+        );
+        assert!(key.strip_prefix("admin_").unwrap().is_none());
+        assert!(key.strip_suffix("_admin").unwrap().is_none());
+
+        // Stripping everything leaves an empty key
+        assert_eq!(key.strip_prefix("user_profile"), Err(KeyParseError::Empty));
+        // The remainder must satisfy start/end rules again
+        assert!(key.strip_prefix("user").is_err());
+        assert!(key.strip_suffix("profile").is_err());
     }
 
     #[test]
-    fn test_key_normalization() {
-        let key = TestKey::new("Test-Key").unwrap();
-        assert_eq!(key.as_str(), "test_key");
+    fn test_split_once() {
+        let none = TestKey::new("user").unwrap();
+        assert_eq!(none.split_once('_'), None);
+        assert_eq!(none.rsplit_once('_'), None);
+
+        let one = TestKey::new("user_tenant").unwrap();
+        assert_eq!(one.split_once('_'), Some(("user", "tenant")));
+        assert_eq!(one.rsplit_once('_'), Some(("user", "tenant")));
+
+        let many = TestKey::new("a_b_c").unwrap();
+        assert_eq!(many.split_once('_'), Some(("a", "b_c")));
+        assert_eq!(many.rsplit_once('_'), Some(("a_b", "c")));
     }
 
     #[test]
-    fn test_domain_validation() {
-        let result = TestKey::new("invalid_key");
-        assert!(result.is_err());
+    fn test_parent() {
+        type PathKey = Key<crate::domain::PathDomain>;
 
-        if let Err(KeyParseError::DomainValidation { domain, message }) = result {
-            assert_eq!(domain, "test");
-            assert!(message.contains("invalid_"));
-        } else {
-            panic!("Expected domain validation error");
+        let key = PathKey::new("users/profile").unwrap();
+        let parent = key.parent().unwrap();
+        assert_eq!(parent.as_str(), "users");
+        assert!(crate::domain::PathDomain::validate_domain_rules(parent.as_str()).is_ok());
+        assert!(parent.parent().is_none());
+
+        assert!(PathKey::new("users").unwrap().parent().is_none());
+
+        // Non-path domains use their own separator
+        let underscored = TestKey::new("a_b_c").unwrap();
+        assert_eq!(underscored.parent().unwrap().as_str(), "a_b");
+    }
+
+    #[test]
+    fn test_nth_part() {
+        let key = TestKey::new("a_bb_ccc").unwrap();
+        assert_eq!(key.nth_part('_', 0), Some("a"));
+        assert_eq!(key.nth_part('_', 2), Some("ccc"));
+        assert_eq!(key.nth_part('_', 3), None);
+        assert_eq!(key.part_count('_'), 3);
+        assert_eq!(key.part_count('-'), 1);
+
+        let cache = key.part_positions('_');
+        for n in 0..4 {
+            assert_eq!(cache.get_part(n), key.nth_part('_', n));
         }
+        assert!(key.parts.0.is_none());
     }
 
     #[test]
-    fn test_common_validation() {
-        // Empty key
-        assert!(matches!(TestKey::new(""), Err(KeyParseError::Empty)));
+    fn test_nth_part_caches_positions() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct DottedDomain;
+        impl KeyDomain for DottedDomain {
+            const DOMAIN_NAME: &'static str = "dotted";
+            const FREQUENTLY_SPLIT: bool = true;
+            fn allowed_characters(c: char) -> bool {
+                c.is_ascii_alphanumeric() || c == '\u{b7}'
+            }
+            fn default_separator() -> char {
+                '\u{b7}'
+            }
+        }
 
-        // Too long key
-        let long_key = "a".repeat(50);
-        assert!(matches!(
-            TestKey::new(&long_key),
-            Err(KeyParseError::TooLong {
-                max_length: 32,
-                actual_length: 50
-            })
+        let key = Key::<crate::PathDomain>::new("tenant/users/42").unwrap();
+        assert_eq!(key.parts.0.as_deref(), Some(&vec![6, 12]));
+        assert_eq!(key.nth_part('/', 1), Some("users"));
+        assert_eq!(key.nth_part('/', 0), Some("tenant"));
+        assert_eq!(key.nth_part('/', 2), Some("42"));
+        assert_eq!(key.nth_part('/', 3), None);
+        assert_eq!(key.part_count('/'), 3);
+
+        // Clones share the recorded positions
+        let copy = key.clone();
+        assert!(PositionList::ptr_eq(
+            key.parts.0.as_ref().unwrap(),
+            copy.parts.0.as_ref().unwrap()
         ));
+        assert_eq!(copy, key);
+
+        // Other delimiters still scan
+        assert_eq!(key.nth_part('s', 1), Some("er"));
+        assert_eq!(key.part_count('s'), 3);
+
+        let dotted = Key::<DottedDomain>::new("ab\u{b7}cd\u{b7}ef").unwrap();
+        for n in 0..4 {
+            assert_eq!(
+                dotted.nth_part('\u{b7}', n),
+                dotted.as_str().split('\u{b7}').nth(n)
+            );
+        }
+        assert_eq!(dotted.part_count('\u{b7}'), 3);
+    }
 
-        // Invalid character
-        let result = TestKey::new("key with spaces");
-        assert!(matches!(
-            result,
-            Err(KeyParseError::InvalidCharacter {
-                character: ' ',
-                position: 3,
-                ..
-            })
-        ));
+    #[test]
+    fn test_common_prefix() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct WideDomain;
+        impl KeyDomain for WideDomain {
+            const DOMAIN_NAME: &'static str = "wide";
+            fn allowed_characters(c: char) -> bool {
+                c.is_alphanumeric() || c == '_'
+            }
+        }
+        type WideKey = Key<WideDomain>;
+
+        let same = TestKey::new("user_profile").unwrap();
+        assert_eq!(same.common_prefix(&same.clone()), "user_profile");
+
+        let shorter = TestKey::new("user").unwrap();
+        assert_eq!(same.common_prefix(&shorter), "user");
+        assert_eq!(shorter.common_prefix(&same), "user");
+
+        let disjoint = TestKey::new("admin").unwrap();
+        assert_eq!(same.common_prefix(&disjoint), "");
+        assert_eq!(same.common_prefix_len(&disjoint), 0);
+
+        // "é" and "è" share their first UTF-8 byte but are different chars
+        let left = WideKey::new("café").unwrap();
+        let right = WideKey::new("cafè").unwrap();
+        assert_eq!(left.common_prefix(&right), "caf");
+        assert_eq!(left.common_prefix_len(&right), 3);
     }
 
     #[test]
-    fn test_hash_caching() {
-        let key1 = TestKey::new("test_key").unwrap();
-        let key2 = TestKey::new("test_key").unwrap();
+    fn test_replace() {
+        let key = TestKey::new("user_v1_profile").unwrap();
 
-        // Same keys should have same hash
-        assert_eq!(key1.hash(), key2.hash());
+        let replaced = key.replace("v1", "v2").unwrap();
+        let fresh = TestKey::new("user_v2_profile").unwrap();
+        assert_eq!(replaced, fresh);
+        assert_eq!(replaced.hash(), fresh.hash());
 
-        let key3 = TestKey::new("different_key").unwrap();
-        // Different keys should have different hashes (with high probability)
-        assert_ne!(key1.hash(), key3.hash());
+        assert!(matches!(
+            key.replace("v1", "v!"),
+            Err(KeyParseError::InvalidCharacter { character: '!', .. })
+        ));
+        assert!(matches!(
+            key.replace("v1", &"x".repeat(32)),
+            Err(KeyParseError::TooLong { .. })
+        ));
+        assert_eq!(key.replace("missing", "x").unwrap(), key);
     }
 
     #[test]
-    fn test_key_methods() {
-        let key = TestKey::new("test_key_example").unwrap();
-        assert!(key.starts_with("test_"));
-        assert!(key.ends_with("_example"));
-        assert!(key.contains("_key_"));
-        assert_eq!(key.len(), 16);
-        assert!(!key.is_empty());
+    fn test_transform() {
+        let key = TestKey::new("abc_123").unwrap();
+
+        let reversed = key.transform(|s| s.chars().rev().collect()).unwrap();
+        let fresh = TestKey::new("321_cba").unwrap();
+        assert_eq!(reversed, fresh);
+        assert_eq!(reversed.hash(), fresh.hash());
+
+        // Output is normalized like any other input
+        let padded = key.transform(|s| format!("  {s}  ")).unwrap();
+        assert_eq!(padded, key);
+
+        // Output is subject to every domain rule
+        assert!(key.transform(|s| format!("invalid_{s}")).is_err());
+        assert!(key.transform(|s| format!("{s}_")).is_err());
+        assert!(key.transform(|_| String::new()).is_err());
     }
 
     #[test]
-    fn test_from_string() {
-        let key = TestKey::from_string("test_key".to_string()).unwrap();
-        assert_eq!(key.as_str(), "test_key");
+    fn test_concat() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct NoRepeatDomain;
+        impl KeyDomain for NoRepeatDomain {
+            const DOMAIN_NAME: &'static str = "no_repeat";
+            fn allowed_consecutive_characters(prev: char, curr: char) -> bool {
+                prev != curr
+            }
+        }
+        type NoRepeatKey = Key<NoRepeatDomain>;
+
+        let left = NoRepeatKey::new("ab").unwrap();
+        let right = NoRepeatKey::new("cd").unwrap();
+        let joined = left.concat(&right, '_').unwrap();
+        assert_eq!(joined, NoRepeatKey::new("ab_cd").unwrap());
+        assert_eq!(joined.hash(), NoRepeatKey::new("ab_cd").unwrap().hash());
+
+        assert!(matches!(
+            left.concat(&right, 'b'),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+
+        let long = TestKey::new("a".repeat(16)).unwrap();
+        assert!(matches!(
+            long.concat(&long, '_'),
+            Err(KeyParseError::TooLong { .. })
+        ));
     }
 
     #[test]
-    fn test_try_from_static() {
-        let key = TestKey::try_from_static("static_key").unwrap();
-        assert_eq!(key.as_str(), "static_key");
+    fn test_join() {
+        type PathKey = Key<crate::domain::PathDomain>;
 
-        let invalid = TestKey::try_from_static("");
-        assert!(invalid.is_err());
+        let parts = ["tenant", "users", "42"].map(|part| PathKey::new(part).unwrap());
+        let joined = PathKey::join(parts, '/').unwrap();
+        assert_eq!(joined, PathKey::new("tenant/users/42").unwrap());
+
+        assert_eq!(PathKey::join(Vec::new(), '/'), Err(KeyParseError::Empty));
     }
 
     #[test]
-    fn test_validation_info() {
-        let key = TestKey::new("test_key").unwrap();
-        let info = key.validation_info();
+    fn test_truncate_to_fit() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct WideDomain;
+        impl KeyDomain for WideDomain {
+            const DOMAIN_NAME: &'static str = "wide";
+            const MAX_LENGTH: usize = 5;
+            fn allowed_characters(c: char) -> bool {
+                c.is_alphanumeric()
+            }
+        }
 
-        assert_eq!(info.domain, "test");
-        assert_eq!(info.max_length, 32);
-        assert_eq!(info.length, 8);
-        assert!(info.has_custom_validation);
-        assert!(info.has_custom_normalization);
+        let long = "a".repeat(100);
+        let clamped = TestKey::truncate_to_fit(&long).unwrap();
+        assert_eq!(clamped.len(), 32);
+
+        // Byte 32 falls right after a separator, which must be trimmed
+        let input = format!("{}_{}", "b".repeat(31), "c".repeat(10));
+        let clamped = TestKey::truncate_to_fit(&input).unwrap();
+        assert_eq!(clamped.as_str(), "b".repeat(31));
+
+        // Short input is not modified, so its own errors still surface
+        assert!(TestKey::truncate_to_fit("ends_").is_err());
+        assert_eq!(TestKey::truncate_to_fit("short").unwrap().as_str(), "short");
+
+        // Cuts never split a multi-byte character
+        let wide = Key::<WideDomain>::truncate_to_fit("ééé").unwrap();
+        assert_eq!(wide.as_str(), "éé");
     }
 
-    #[cfg(feature = "serde")]
     #[test]
-    fn test_serde() {
-        let key = TestKey::new("test_key").unwrap();
+    fn test_ascii_fast_path_matches_unicode_rules() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct WideDomain;
+        impl KeyDomain for WideDomain {
+            const DOMAIN_NAME: &'static str = "wide";
+            fn allowed_characters(c: char) -> bool {
+                c.is_alphanumeric() || c == '_'
+            }
+        }
 
-        // Test JSON serialization
-        let json = serde_json::to_string(&key).unwrap();
-        assert_eq!(json, r#""test_key""#);
+        // ASCII input takes the byte-wise path
+        assert!(Key::<WideDomain>::new("plain_ascii").is_ok());
+        match Key::<WideDomain>::new("bad key") {
+            Err(KeyParseError::InvalidCharacter {
+                character,
+                position,
+                ..
+            }) => {
+                assert_eq!(character, ' ');
+                assert_eq!(position, 3);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
 
-        // Test JSON deserialization
-        let deserialized: TestKey = serde_json::from_str(&json).unwrap();
-        assert_eq!(deserialized, key);
+        // Non-ASCII input still takes the Unicode path
+        assert!(Key::<WideDomain>::new("ünïcode").is_ok());
+        match Key::<WideDomain>::new("ü key") {
+            Err(KeyParseError::InvalidCharacter { position, .. }) => assert_eq!(position, 2),
+            other => panic!("unexpected result: {other:?}"),
+        }
     }
 
+    #[cfg(all(feature = "std", feature = "shared"))]
     #[test]
-    fn test_from_parts() {
-        let key = TestKey::from_parts(&["user", "123", "profile"], "_").unwrap();
-        assert_eq!(key.as_str(), "user_123_profile");
+    fn test_interner() {
+        let mut interner = Interner::<TestDomain>::new();
+        assert!(interner.is_empty());
+
+        let first = interner.intern("tenant_a").unwrap();
+        let second = interner.intern("  TENANT_A ").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(interner.len(), 1);
+
+        interner.intern("tenant_b").unwrap();
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("tenant_a"), Some(&first));
+        assert_eq!(interner.get("tenant_c"), None);
+
+        // Ids are dense, stable and reversible
+        let b = interner.get("tenant_b").unwrap().clone();
+        assert_eq!(interner.intern_id(&first), Some(0));
+        assert_eq!(interner.intern_id(&b), Some(1));
+        assert_eq!(interner.from_intern_id(1), Some(&b));
+        assert_eq!(interner.from_intern_id(2), None);
+        assert_eq!(interner.intern_id(&TestKey::new("tenant_c").unwrap()), None);
+
+        // Validation still runs and rejected input is not stored
+        assert!(interner.intern("bad key").is_err());
+        assert!(matches!(
+            interner.intern("invalid_tenant"),
+            Err(KeyParseError::DomainValidation { .. })
+        ));
+        assert_eq!(interner.len(), 2);
+    }
 
-        let parts: Vec<&str> = key.split('_').collect();
-        assert_eq!(parts, vec!["user", "123", "profile"]);
+    #[cfg(all(feature = "std", feature = "shared"))]
+    #[test]
+    fn test_interner_shares_storage() {
+        let mut interner = Interner::<TestDomain>::new();
+        let first = interner.intern("tenant_a").unwrap();
+        let second = interner.intern("TENANT_A").unwrap();
+        let other = interner.intern("tenant_b").unwrap();
+
+        assert!(Storage::ptr_eq(&first.inner, &second.inner));
+        assert!(!Storage::ptr_eq(&first.inner, &other.inner));
     }
 
     #[test]
-    fn test_ensure_prefix_suffix() {
-        let key = TestKey::new("profile").unwrap();
+    fn test_stable_hash() {
+        let key = TestKey::new("hello").unwrap();
+        assert_eq!(key.stable_hash(), 0xa430_d846_80aa_bd0b);
+        assert_eq!(
+            TestKey::new("  HELLO ").unwrap().stable_hash(),
+            key.stable_hash()
+        );
+        assert_ne!(
+            TestKey::new("world").unwrap().stable_hash(),
+            key.stable_hash()
+        );
+    }
 
-        let prefixed = key.ensure_prefix("user_").unwrap();
-        assert_eq!(prefixed.as_str(), "user_profile");
+    #[test]
+    fn test_depth() {
+        assert_eq!(TestKey::new("single").unwrap().depth(), 1);
+        assert_eq!(TestKey::new("a_b_c").unwrap().depth(), 3);
 
-        // Already has prefix
-        let same = prefixed.ensure_prefix("user_").unwrap();
-        assert_eq!(same.as_str(), "user_profile");
+        let path = Key::<crate::PathDomain>::new("a/b_c/d").unwrap();
+        assert_eq!(path.depth(), 3);
+        assert_eq!(path.depth(), path.ancestors().count() + 1);
+    }
 
-        let suffixed = key.ensure_suffix("_v1").unwrap();
-        assert_eq!(suffixed.as_str(), "profile_v1");
+    #[test]
+    fn test_common_ancestor() {
+        type PathKey = Key<crate::PathDomain>;
+        let path = |s: &str| PathKey::new(s).unwrap();
+
+        assert_eq!(
+            path("a/b/c").common_ancestor(&path("a/b/d")),
+            Some(path("a/b"))
+        );
+        assert_eq!(path("a/bc").common_ancestor(&path("a/bd")), Some(path("a")));
+        assert_eq!(
+            path("a/b").common_ancestor(&path("a/b/c")),
+            Some(path("a/b"))
+        );
+        assert_eq!(path("a/b").common_ancestor(&path("a/b")), Some(path("a/b")));
+        assert_eq!(path("ab").common_ancestor(&path("ac")), None);
+    }
 
-        // Already has suffix
-        let same = suffixed.ensure_suffix("_v1").unwrap();
-        assert_eq!(same.as_str(), "profile_v1");
+    #[test]
+    fn test_ancestors() {
+        type PathKey = Key<crate::PathDomain>;
+
+        let key = PathKey::new("tenant/users/42").unwrap();
+        let ancestors: Vec<PathKey> = key.ancestors().collect();
+        assert_eq!(
+            ancestors,
+            vec![
+                PathKey::new("tenant").unwrap(),
+                PathKey::new("tenant/users").unwrap()
+            ]
+        );
+        assert_eq!(
+            ancestors[1].hash(),
+            PathKey::new("tenant/users").unwrap().hash()
+        );
+
+        assert_eq!(PathKey::new("root").unwrap().ancestors().count(), 0);
+
+        // "a." is not a valid key on its own, so it is skipped
+        let key = Key::<DefaultDomain>::new("a._b_c").unwrap();
+        let ancestors: Vec<Key<DefaultDomain>> = key.ancestors().collect();
+        assert_eq!(ancestors, vec![Key::<DefaultDomain>::new("a._b").unwrap()]);
+    }
+
+    #[test]
+    fn test_versioning() {
+        let key = TestKey::new("config").unwrap();
+        assert_eq!(key.version(), None);
+
+        let v2 = key.with_version(2).unwrap();
+        assert_eq!(v2.as_str(), "config_v2");
+        assert_eq!(v2.version(), Some(2));
+
+        let v13 = v2.with_version(13).unwrap();
+        assert_eq!(v13.as_str(), "config_v13");
+        assert_eq!(v13.with_version(13).unwrap(), v13);
+
+        // Not a version suffix
+        assert_eq!(TestKey::new("config_vx").unwrap().version(), None);
+        assert_eq!(TestKey::new("config_v").unwrap().version(), None);
+        assert_eq!(TestKey::new("config_v99999999999").unwrap().version(), None);
+        assert_eq!(
+            TestKey::new("config_vx")
+                .unwrap()
+                .with_version(1)
+                .unwrap()
+                .as_str(),
+            "config_vx_v1"
+        );
     }
 
     #[test]
@@ -1611,12 +6654,75 @@ mod tests {
         assert_eq!(string, "example");
     }
 
+    #[cfg(feature = "shared")]
+    #[test]
+    fn test_shared_storage_clone() {
+        let key = TestKey::new("a_key_longer_than_inline_size").unwrap();
+        let copy = key.clone();
+        assert_eq!(copy, key);
+        assert_eq!(copy.hash(), key.hash());
+        assert!(core::ptr::eq(copy.as_str(), key.as_str()));
+    }
+
+    #[test]
+    fn test_into_string() {
+        let short = TestKey::new("short").unwrap();
+        assert_eq!(short.into_string(), "short");
+
+        let text = "a_considerably_long_key_name";
+        assert!(text.len() > 23);
+        let long = TestKey::new(text).unwrap();
+        let string = long.into_string();
+        assert_eq!(string, text);
+        assert_eq!(TestKey::from_string(string).unwrap().as_str(), text);
+    }
+
     #[test]
     fn test_from_str() {
         let key: TestKey = "example".parse().unwrap();
         assert_eq!(key.as_str(), "example");
     }
 
+    #[test]
+    fn test_try_from() {
+        let key = Key::<TestDomain>::try_from("ok").unwrap();
+        assert_eq!(key.as_str(), "ok");
+        let owned: TestKey = Key::try_from(String::from("ok")).unwrap();
+        assert_eq!(owned, key);
+
+        assert!(matches!(
+            TestKey::try_from("bad key"),
+            Err(KeyParseError::InvalidCharacter { character: ' ', .. })
+        ));
+        assert!(matches!(
+            TestKey::try_from(String::new()),
+            Err(KeyParseError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let key = TestKey::try_from(&b"from_wire"[..]).unwrap();
+        assert_eq!(key.as_str(), "from_wire");
+        let owned = TestKey::try_from(b"from_wire".to_vec()).unwrap();
+        assert_eq!(owned, key);
+
+        let invalid: &[u8] = b"ab\xffcd";
+        assert_eq!(
+            TestKey::try_from(invalid),
+            Err(KeyParseError::InvalidUtf8 { position: 2 })
+        );
+        assert_eq!(
+            TestKey::try_from(invalid.to_vec()),
+            Err(KeyParseError::InvalidUtf8 { position: 2 })
+        );
+
+        assert!(matches!(
+            TestKey::try_from(&b"invalid_key"[..]),
+            Err(KeyParseError::DomainValidation { .. })
+        ));
+    }
+
     #[test]
     fn test_default_domain() {
         type DefaultKey = Key<DefaultDomain>;
@@ -1646,4 +6752,215 @@ mod tests {
         let str_parts: Vec<&str> = key.split_str("_").collect();
         assert_eq!(str_parts, vec!["user", "profile", "settings"]);
     }
+
+    #[test]
+    fn test_content_eq() {
+        type DefaultKey = Key<DefaultDomain>;
+
+        let typed = TestKey::new("user_profile").unwrap();
+        let mirror = DefaultKey::new("user_profile").unwrap();
+        let other = DefaultKey::new("user_settings").unwrap();
+
+        assert!(typed.content_eq(&mirror));
+        assert!(mirror.content_eq(&typed));
+        assert!(!typed.content_eq(&other));
+    }
+
+    #[test]
+    fn test_index_bytes_order() {
+        use crate::domain::PathDomain;
+        type PathKey = Key<PathDomain>;
+
+        let mut keys: Vec<PathKey> = ["a/bc", "a-b", "a/b/c", "a/b", "a", "ab"]
+            .iter()
+            .map(|s| PathKey::new(s).unwrap())
+            .collect();
+        keys.sort_by_key(Key::index_bytes);
+
+        let sorted: Vec<&str> = keys.iter().map(Key::as_str).collect();
+        assert_eq!(sorted, vec!["a", "a/b", "a/b/c", "a/bc", "a-b", "ab"]);
+
+        for key in &keys {
+            assert_eq!(&PathKey::from_index_bytes(&key.index_bytes()).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_index_bytes_plain_and_invalid() {
+        let key = TestKey::new("user_profile").unwrap();
+        let bytes = key.index_bytes();
+        assert_eq!(bytes, b"user\0profile");
+        assert_eq!(TestKey::from_index_bytes(&bytes).unwrap(), key);
+
+        assert!(matches!(
+            TestKey::from_index_bytes(&[b'a', 0x01, 0x07]),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+        assert!(matches!(
+            TestKey::from_index_bytes(&[b'a', 0xff]),
+            Err(KeyParseError::InvalidUtf8 { position: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_split_at_segment() {
+        let key = TestKey::new("user_profile_settings").unwrap();
+
+        let (head, tail) = key.split_at_segment(1).unwrap();
+        assert_eq!(head.as_str(), "user");
+        assert_eq!(tail.as_str(), "profile_settings");
+
+        let (head, tail) = key.split_at_segment(2).unwrap();
+        assert_eq!(head.as_str(), "user_profile");
+        assert_eq!(tail.as_str(), "settings");
+
+        assert!(key.split_at_segment(0).is_none());
+        assert!(key.split_at_segment(3).is_none());
+
+        // Halves are re-validated against the domain rules
+        let key = TestKey::new("user_invalid_rest").unwrap();
+        assert!(key.split_at_segment(1).is_none());
+        assert!(key.split_at_segment(2).is_some());
+    }
+
+    #[test]
+    fn test_rehash_key_uses_content() {
+        use core::hash::BuildHasher;
+
+        struct ByteCounter(u64);
+        impl Hasher for ByteCounter {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+            fn write(&mut self, bytes: &[u8]) {
+                self.0 += bytes.len() as u64;
+            }
+        }
+        struct BuildByteCounter;
+        impl BuildHasher for BuildByteCounter {
+            type Hasher = ByteCounter;
+            fn build_hasher(&self) -> ByteCounter {
+                ByteCounter(0)
+            }
+        }
+
+        let key = TestKey::new("user_profile").unwrap();
+        let rehashed = RehashKey::new(key.clone());
+
+        // The custom hasher sees the content, hashing the same as `str`
+        assert_eq!(
+            BuildByteCounter.hash_one(&rehashed),
+            BuildByteCounter.hash_one("user_profile")
+        );
+        // The plain key only writes its cached u64
+        assert_eq!(BuildByteCounter.hash_one(&key), 8);
+
+        let borrowed: &str = rehashed.borrow();
+        assert_eq!(borrowed, "user_profile");
+        assert_eq!(rehashed.into_inner(), key);
+    }
+
+    #[test]
+    fn test_map_segments() {
+        let key = TestKey::new("user_john_profile").unwrap();
+
+        let reversed = key.map_segments(|s| s.chars().rev().collect()).unwrap();
+        assert_eq!(reversed.as_str(), "resu_nhoj_eliforp");
+
+        assert!(matches!(
+            key.map_segments(|s| if s == "john" {
+                String::new()
+            } else {
+                s.to_string()
+            }),
+            Err(KeyParseError::InvalidStructure { .. })
+        ));
+        assert!(matches!(
+            key.map_segments(|s| format!("{s}!")),
+            Err(KeyParseError::InvalidCharacter { character: '!', .. })
+        ));
+    }
+
+    #[test]
+    fn test_domain_character_hooks_are_authoritative() {
+        type DefaultKey = Key<DefaultDomain>;
+        type IdentKey = Key<crate::domain::IdentifierDomain>;
+
+        // No built-in ASCII shortcut overrides the start-character rules
+        for input in ["_foo", "-x", ".x"] {
+            assert!(
+                matches!(
+                    DefaultKey::new(input),
+                    Err(KeyParseError::InvalidCharacter { position: 0, .. })
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+        assert!(DefaultKey::new("foo_bar-baz.qux").is_ok());
+
+        // ...or lets `-`, `_` and `.` into narrower domains
+        assert!(matches!(
+            IdentKey::new("a-b"),
+            Err(KeyParseError::InvalidCharacter { character: '-', .. })
+        ));
+        assert!(matches!(
+            IdentKey::new("foo.bar"),
+            Err(KeyParseError::InvalidCharacter { character: '.', .. })
+        ));
+        assert!(IdentKey::new("foo_bar").is_ok());
+    }
+
+    #[test]
+    fn test_to_case() {
+        use crate::domain::IdentifierDomain;
+        type IdKey = Key<IdentifierDomain>;
+
+        let key = IdKey::new("user_profile_id").unwrap();
+        assert_eq!(
+            key.to_case(CaseStyle::Camel).unwrap().as_str(),
+            "userProfileId"
+        );
+        assert_eq!(
+            key.to_case(CaseStyle::Pascal).unwrap().as_str(),
+            "UserProfileId"
+        );
+        assert_eq!(
+            key.to_case(CaseStyle::Snake).unwrap().as_str(),
+            "user_profile_id"
+        );
+
+        let camel = IdKey::new("userProfileId").unwrap();
+        assert_eq!(
+            camel.to_case(CaseStyle::Snake).unwrap().as_str(),
+            "user_profile_id"
+        );
+
+        // Identifiers forbid '-', so kebab conversion must fail validation
+        assert!(matches!(
+            key.to_case(CaseStyle::Kebab),
+            Err(KeyParseError::InvalidCharacter { character: '-', .. })
+        ));
+
+        let key = TestKey::new("user_profile").unwrap();
+        // TestDomain normalizes '-' back to '_'
+        assert_eq!(
+            key.to_case(CaseStyle::Kebab).unwrap().as_str(),
+            "user_profile"
+        );
+        // Case-insensitive domains lowercase camel output
+        assert_eq!(
+            key.to_case(CaseStyle::Camel).unwrap().as_str(),
+            "userprofile"
+        );
+    }
+
+    #[test]
+    fn test_split_at_segment_path_domain() {
+        use crate::domain::PathDomain;
+
+        let key = Key::<PathDomain>::new("api/v1/users").unwrap();
+        let (head, tail) = key.split_at_segment(1).unwrap();
+        assert_eq!(head.as_str(), "api");
+        assert_eq!(tail.as_str(), "v1/users");
+    }
 }
@@ -234,6 +234,8 @@ pub mod domain;
 pub mod error;
 pub mod features;
 pub mod key;
+#[cfg(feature = "serde")]
+pub mod serde_tagged;
 pub mod utils;
 pub mod validation;
 
@@ -247,16 +249,31 @@ mod macros;
 // ============================================================================
 
 // Core types
-pub use domain::{domain_info, DefaultDomain, IdentifierDomain, KeyDomain, PathDomain};
+pub use domain::{
+    domain_info, DefaultDomain, DynamicDomain, IdentifierDomain, KeyDomain, PathDomain,
+    RuntimeDomain, SubdomainOf,
+};
 pub use error::{ErrorCategory, KeyParseError};
 pub use key::Key;
 
 // Helper types
-pub use key::{KeyValidationInfo, SplitCache, SplitIterator};
+#[cfg(feature = "serde")]
+pub use key::CompactKey;
+#[cfg(all(feature = "std", feature = "shared"))]
+pub use key::Interner;
+#[cfg(feature = "std")]
+pub use key::KeyMap;
+pub use key::{
+    BuildKeyHasher, CaseStyle, EscapeContext, KeyHasher, KeyValidationInfo, RehashKey, SplitCache,
+    SplitIterator,
+};
 pub use validation::IntoKey;
 
 // Utility functions
-pub use features::{hash_algorithm, performance_info, PerformanceInfo};
+pub use features::{hash_algorithm, hash_str, performance_info, PerformanceInfo};
+#[cfg(all(feature = "secure", feature = "std", not(feature = "fast")))]
+pub use features::{hash_seed, set_hash_seed};
+pub use utils::char_validation::CharTable;
 pub use utils::new_split_cache;
 pub use validation::*;
 
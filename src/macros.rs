@@ -319,6 +319,10 @@ mod tests {
         let key = static_key!(MacroTestKey, "static_test");
         assert_eq!(key.as_str(), "static_test");
         assert_eq!(key.domain(), "macro_test");
+
+        // Static keys normalize exactly like runtime keys
+        let upper = static_key!(MacroTestKey, "FOO");
+        assert_eq!(upper, MacroTestKey::new("foo").unwrap());
     }
 
     #[test]
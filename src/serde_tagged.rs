@@ -0,0 +1,162 @@
+//! Serde adapter that tags keys with their domain name
+//!
+//! Use this module with `#[serde(with = "domain_key::serde_tagged")]` to
+//! serialize a key as `"domain:key"`, the same format as its `Display`
+//! output for non-redacting domains. The content is always written in full,
+//! even for domains with `REDACT_IN_DISPLAY`. Deserialization checks that
+//! the prefix equals the target domain's `DOMAIN_NAME` before validating the
+//! rest as a key, so a key from another domain is rejected instead of
+//! silently accepted.
+//!
+//! The plain `Serialize`/`Deserialize` implementations of [`Key`] are
+//! unaffected and stay untagged.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use domain_key::{Key, KeyDomain};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+//! struct UserDomain;
+//! impl KeyDomain for UserDomain {
+//!     const DOMAIN_NAME: &'static str = "user";
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "domain_key::serde_tagged")]
+//!     actor: Key<UserDomain>,
+//! }
+//!
+//! let event = Event { actor: Key::new("alice")? };
+//! let json = serde_json::to_string(&event).unwrap();
+//! assert_eq!(json, r#"{"actor":"user:alice"}"#);
+//!
+//! let back: Event = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.actor.as_str(), "alice");
+//! assert!(serde_json::from_str::<Event>(r#"{"actor":"order:alice"}"#).is_err());
+//! # Ok::<(), domain_key::KeyParseError>(())
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::domain::KeyDomain;
+use crate::key::Key;
+
+/// Serializes a key as `"domain:key"`
+///
+/// # Errors
+///
+/// Returns the serializer's error if writing the string fails
+pub fn serialize<T, S>(key: &Key<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: KeyDomain,
+    S: serde::Serializer,
+{
+    // Not `Display`, which redacts keys of `REDACT_IN_DISPLAY` domains
+    serializer.collect_str(&format_args!("{}:{}", T::DOMAIN_NAME, key.as_str()))
+}
+
+/// Deserializes a `"domain:key"` string, checking the domain prefix
+///
+/// # Errors
+///
+/// Returns a deserialization error if the prefix is missing or names another
+/// domain, or if the remainder is not a valid key
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Key<T>, D::Error>
+where
+    T: KeyDomain,
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(TaggedVisitor(PhantomData))
+}
+
+/// Visitor that strips and checks the domain prefix
+struct TaggedVisitor<T: KeyDomain>(PhantomData<T>);
+
+impl<T: KeyDomain> serde::de::Visitor<'_> for TaggedVisitor<T> {
+    type Value = Key<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a key string tagged as \"{}:<key>\"", T::DOMAIN_NAME)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let key = value
+            .strip_prefix(T::DOMAIN_NAME)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or_else(|| {
+                E::custom(format_args!(
+                    "expected domain prefix '{}:' in {value:?}",
+                    T::DOMAIN_NAME
+                ))
+            })?;
+        Key::new(key).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DefaultDomain;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Tagged {
+        #[serde(with = "crate::serde_tagged")]
+        key: Key<DefaultDomain>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let value = Tagged {
+            key: Key::new("some_key").unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"key":"default:some_key"}"#);
+        assert_eq!(serde_json::from_str::<Tagged>(&json).unwrap(), value);
+
+        // The default implementation stays untagged
+        assert_eq!(serde_json::to_string(&value.key).unwrap(), r#""some_key""#);
+    }
+
+    #[test]
+    fn test_round_trip_redacting_domain() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct SecretDomain;
+        impl KeyDomain for SecretDomain {
+            const DOMAIN_NAME: &'static str = "secret";
+            const REDACT_IN_DISPLAY: bool = true;
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Secret {
+            #[serde(with = "crate::serde_tagged")]
+            key: Key<SecretDomain>,
+        }
+
+        let value = Secret {
+            key: Key::new("api_token").unwrap(),
+        };
+        assert!(!value.key.to_string().contains("api_token"));
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"key":"secret:api_token"}"#);
+        assert_eq!(serde_json::from_str::<Secret>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_rejects_mismatched_prefix() {
+        let err = serde_json::from_str::<Tagged>(r#"{"key":"other:some_key"}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected domain prefix 'default:'"));
+        assert!(serde_json::from_str::<Tagged>(r#"{"key":"some_key"}"#).is_err());
+        assert!(serde_json::from_str::<Tagged>(r#"{"key":"defaultsome_key"}"#).is_err());
+
+        // The remainder is still validated
+        let err = serde_json::from_str::<Tagged>(r#"{"key":"default:bad key"}"#).unwrap_err();
+        assert!(err.to_string().contains("Invalid character"));
+    }
+}
@@ -283,6 +283,81 @@ pub mod char_validation {
         table
     };
 
+    /// Compile-time lookup table of allowed ASCII characters
+    ///
+    /// Lets a domain declare its allowed character set once as a constant
+    /// instead of branching per character in `allowed_characters`.
+    /// Non-ASCII characters are never contained.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{CharTable, KeyDomain};
+    ///
+    /// const ALLOWED: CharTable = CharTable::ascii_alphanumeric().with('_').with('/');
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct RouteDomain;
+    /// impl KeyDomain for RouteDomain {
+    ///     const DOMAIN_NAME: &'static str = "route";
+    ///
+    ///     fn allowed_characters(c: char) -> bool {
+    ///         ALLOWED.contains(c)
+    ///     }
+    /// }
+    ///
+    /// assert!(ALLOWED.contains('/'));
+    /// assert!(!ALLOWED.contains('-'));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CharTable {
+        table: [bool; 128],
+    }
+
+    impl CharTable {
+        /// Create a table that contains no characters
+        #[must_use]
+        pub const fn empty() -> Self {
+            Self {
+                table: [false; 128],
+            }
+        }
+
+        /// Create a table containing ASCII letters and digits
+        #[must_use]
+        pub const fn ascii_alphanumeric() -> Self {
+            Self {
+                table: ASCII_ALPHANUMERIC,
+            }
+        }
+
+        /// Add a character to the table
+        ///
+        /// # Panics
+        ///
+        /// Panics if `c` is not ASCII. In a `const` context this is a
+        /// compile-time error.
+        #[must_use]
+        pub const fn with(mut self, c: char) -> Self {
+            assert!(c.is_ascii(), "CharTable only holds ASCII characters");
+            self.table[c as usize] = true;
+            self
+        }
+
+        /// Check whether the table contains a character
+        #[inline]
+        #[must_use]
+        pub const fn contains(&self, c: char) -> bool {
+            c.is_ascii() && self.table[c as usize]
+        }
+    }
+
+    impl Default for CharTable {
+        fn default() -> Self {
+            Self::empty()
+        }
+    }
+
     /// Fast check if a character is ASCII alphanumeric
     #[inline]
     #[must_use]
@@ -485,14 +560,14 @@ impl PositionCache {
             i if i == self.positions.len() => {
                 // Last part: from last delimiter to end
                 if let Some(&last_pos) = self.positions.last() {
-                    Some(&s[last_pos + 1..])
+                    Some(&s[last_pos + self.delimiter.len_utf8()..])
                 } else {
                     None // No delimiters but asking for part > 0
                 }
             }
             i if i < self.positions.len() => {
                 // Middle part: between two delimiters
-                let start = self.positions[i - 1] + 1;
+                let start = self.positions[i - 1] + self.delimiter.len_utf8();
                 let end = self.positions[i];
                 Some(&s[start..end])
             }
@@ -513,15 +588,27 @@ pub mod benchmark {
     use alloc::vec::Vec;
 
     /// Simple timer for measuring operation duration
+    ///
+    /// `std::time::Instant` panics on `wasm32-unknown-unknown`, so that
+    /// target uses the same zero-duration timer as `no_std` builds.
     #[derive(Debug)]
     pub struct Timer {
-        #[cfg(feature = "std")]
+        #[cfg(all(
+            feature = "std",
+            not(all(target_arch = "wasm32", target_os = "unknown"))
+        ))]
         start: std::time::Instant,
-        #[cfg(not(feature = "std"))]
+        #[cfg(not(all(
+            feature = "std",
+            not(all(target_arch = "wasm32", target_os = "unknown"))
+        )))]
         _phantom: core::marker::PhantomData<()>,
     }
 
-    #[cfg(feature = "std")]
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
     impl Timer {
         /// Start a new timer
         #[must_use]
@@ -545,9 +632,12 @@ pub mod benchmark {
         }
     }
 
-    #[cfg(not(feature = "std"))]
+    #[cfg(not(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    )))]
     impl Timer {
-        /// Start a new timer (no-op in no_std)
+        /// Start a new timer (no-op in `no_std` and on bare wasm)
         pub fn start() -> Self {
             Self {
                 _phantom: core::marker::PhantomData,
@@ -951,6 +1041,31 @@ mod tests {
         assert!(!is_whitespace_fast('a'));
     }
 
+    #[test]
+    fn test_char_table() {
+        use char_validation::CharTable;
+
+        const TABLE: CharTable = CharTable::ascii_alphanumeric().with('_').with('/');
+
+        assert!(TABLE.contains('a'));
+        assert!(TABLE.contains('Z'));
+        assert!(TABLE.contains('7'));
+        assert!(TABLE.contains('_'));
+        assert!(TABLE.contains('/'));
+        assert!(!TABLE.contains('-'));
+        assert!(!TABLE.contains('é'));
+        assert!(!CharTable::empty().contains('a'));
+        assert_eq!(CharTable::default(), CharTable::empty());
+
+        for b in 0u8..128 {
+            let c = char::from(b);
+            assert_eq!(
+                TABLE.contains(c),
+                c.is_ascii_alphanumeric() || c == '_' || c == '/'
+            );
+        }
+    }
+
     #[test]
     fn test_string_utilities() {
         assert!(is_ascii_only("hello"));
@@ -997,6 +1112,16 @@ mod tests {
         assert!(!cache.is_valid_for("different", '_'));
     }
 
+    #[test]
+    fn test_position_cache_multibyte_delimiter() {
+        let cache = PositionCache::new("ab\u{b7}cd\u{b7}ef", '\u{b7}');
+        assert_eq!(cache.part_count(), 3);
+        assert_eq!(cache.get_part(0), Some("ab"));
+        assert_eq!(cache.get_part(1), Some("cd"));
+        assert_eq!(cache.get_part(2), Some("ef"));
+        assert_eq!(cache.get_part(3), None);
+    }
+
     #[test]
     fn test_memory_utilities() {
         let s = "hello";
@@ -1008,6 +1133,13 @@ mod tests {
         assert!(capacity.is_power_of_two() || capacity == 32);
     }
 
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    #[test]
+    fn test_wasm_timer_falls_back_to_zero() {
+        let timer = benchmark::Timer::start();
+        assert_eq!(timer.elapsed_nanos(), 0);
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_benchmark_utilities() {
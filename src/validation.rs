@@ -4,7 +4,7 @@
 //! validation without key creation, batch validation, and helper traits
 //! for converting various types into keys.
 
-use crate::domain::KeyDomain;
+use crate::domain::{self, DynamicDomain, KeyDomain, RuntimeDomain};
 use crate::error::KeyParseError;
 use crate::key::Key;
 
@@ -17,12 +17,46 @@ use alloc::vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-use core::fmt::Write;
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::io::BufRead;
 
 // ============================================================================
 // VALIDATION FUNCTIONS
 // ============================================================================
 
+/// Check only whether a string's length is within the domain's limits
+///
+/// This is a cheap pre-filter for large candidate lists: it compares the
+/// byte length against `min_length()..=MAX_LENGTH` and does not trim,
+/// normalize or inspect characters. A `true` result does not mean the key
+/// is valid; run [`validate_key`] on the survivors.
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+///     const MAX_LENGTH: usize = 8;
+/// }
+///
+/// assert!(validation::length_ok::<TestDomain>("short"));
+/// assert!(!validation::length_ok::<TestDomain>("far_too_long"));
+/// assert!(!validation::length_ok::<TestDomain>(""));
+/// ```
+#[inline]
+#[must_use]
+pub fn length_ok<T: KeyDomain>(key: &str) -> bool {
+    (T::min_length()..=T::MAX_LENGTH).contains(&key.len())
+}
+
 /// Check if a string would be a valid key for a domain without creating the key
 ///
 /// This is useful for pre-validation or filtering operations where you don't
@@ -75,8 +109,40 @@ pub fn is_valid_key<T: KeyDomain>(key: &str) -> bool {
 /// ```
 pub fn validate_key<T: KeyDomain>(key: &str) -> Result<(), KeyParseError> {
     Key::<T>::validate_common::<T>(key)?;
-    let normalized = Key::<T>::normalize::<T>(key);
-    T::validate_domain_rules(&normalized)
+    let normalized = T::canonicalize(Key::<T>::normalize_common::<T>(key))?;
+    T::post_validate(&normalized, Key::<T>::compute_hash(&normalized))
+}
+
+/// Validate a key string and return its canonical form
+///
+/// Combines [`validate_key`] with normalization and returns the exact string
+/// that `Key::new` would store, without constructing a key. This is useful
+/// when the downstream store wants a `String` rather than a `Key`.
+///
+/// # Errors
+///
+/// Returns `KeyParseError` if the key fails common or domain-specific validation
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+///
+/// assert_eq!(validation::canonicalize::<TestDomain>("  User_Name ")?, "user_name");
+/// assert!(validation::canonicalize::<TestDomain>("").is_err());
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+pub fn canonicalize<T: KeyDomain>(input: &str) -> Result<String, KeyParseError> {
+    Key::<T>::validate_common::<T>(input)?;
+    let normalized = T::canonicalize(Key::<T>::normalize_common::<T>(input))?;
+    T::post_validate(&normalized, Key::<T>::compute_hash(&normalized))?;
+    Ok(normalized.into_owned())
 }
 
 /// Get validation help text for a domain
@@ -132,40 +198,70 @@ pub fn validation_help<T: KeyDomain>() -> Option<&'static str> {
 /// ```
 #[must_use]
 pub fn validation_info<T: KeyDomain>() -> String {
-    let mut info = format!("Domain: {}\n", T::DOMAIN_NAME);
-    writeln!(info, "Max length: {}", T::MAX_LENGTH).unwrap();
-    writeln!(info, "Min length: {}", T::min_length()).unwrap();
-    writeln!(info, "Expected length: {}", T::EXPECTED_LENGTH).unwrap();
-    writeln!(info, "Case insensitive: {}", T::CASE_INSENSITIVE).unwrap();
-    writeln!(info, "Custom validation: {}", T::HAS_CUSTOM_VALIDATION).unwrap();
-    writeln!(
-        info,
-        "Custom normalization: {}",
-        T::HAS_CUSTOM_NORMALIZATION,
-    )
-    .unwrap();
-
-    writeln!(info, "Default separator: '{}'", T::default_separator()).unwrap();
+    let mut info = String::new();
+    // Writing to a String cannot fail
+    let _ = validation_info_fmt::<T, _>(&mut info);
+    info
+}
+
+/// Write detailed information about validation rules for a domain
+///
+/// Produces the same content as [`validation_info`] but writes it directly
+/// to any `core::fmt::Write` sink, such as a `Formatter` inside a `Display`
+/// implementation, without allocating.
+///
+/// # Errors
+///
+/// Returns `fmt::Error` if the underlying writer fails
+///
+/// # Examples
+///
+/// ```rust
+/// use core::fmt;
+/// use domain_key::{KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+///
+/// struct Diagnostics;
+/// impl fmt::Display for Diagnostics {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         validation::validation_info_fmt::<TestDomain, _>(f)
+///     }
+/// }
+///
+/// assert_eq!(Diagnostics.to_string(), validation::validation_info::<TestDomain>());
+/// ```
+pub fn validation_info_fmt<T: KeyDomain, W: Write + ?Sized>(w: &mut W) -> fmt::Result {
+    writeln!(w, "Domain: {}", T::DOMAIN_NAME)?;
+    writeln!(w, "Max length: {}", T::MAX_LENGTH)?;
+    writeln!(w, "Min length: {}", T::min_length())?;
+    writeln!(w, "Expected length: {}", T::EXPECTED_LENGTH)?;
+    writeln!(w, "Case insensitive: {}", T::CASE_INSENSITIVE)?;
+    writeln!(w, "Custom validation: {}", T::HAS_CUSTOM_VALIDATION)?;
+    writeln!(w, "Custom normalization: {}", T::HAS_CUSTOM_NORMALIZATION)?;
+    writeln!(w, "Default separator: '{}'", T::default_separator())?;
 
     if let Some(help) = T::validation_help() {
-        info.push_str("Help: ");
-        info.push_str(help);
-        info.push('\n');
+        writeln!(w, "Help: {help}")?;
     }
 
     let examples = T::examples();
     if !examples.is_empty() {
-        info.push_str("Examples: ");
+        w.write_str("Examples: ")?;
         for (i, example) in examples.iter().enumerate() {
             if i > 0 {
-                info.push_str(", ");
+                w.write_str(", ")?;
             }
-            info.push_str(example);
+            w.write_str(example)?;
         }
-        info.push('\n');
+        w.write_char('\n')?;
     }
 
-    info
+    Ok(())
 }
 
 /// Validate multiple keys at once
@@ -219,6 +315,232 @@ where
     (valid, invalid)
 }
 
+/// Validate multiple keys at once, deduplicating and counting valid keys
+///
+/// This function validates a collection of keys, constructs each valid key
+/// and tallies how often each unique key appeared. Deduplication uses key
+/// identity, so inputs that normalize to the same key are counted together.
+///
+/// # Arguments
+///
+/// * `keys` - Iterator of string-like items to validate
+///
+/// # Returns
+///
+/// A tuple containing:
+/// - Map of unique valid keys to their occurrence counts
+/// - Vector of (invalid key string, error) pairs
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{Key, KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+///
+/// let keys = vec!["user_1", "USER_1", "user_2", "bad key"];
+/// let (counts, invalid) = validation::validate_batch_counted::<TestDomain, _>(keys);
+///
+/// assert_eq!(counts.len(), 2);
+/// assert_eq!(counts[&Key::<TestDomain>::new("user_1")?], 2);
+/// assert_eq!(invalid.len(), 1);
+/// # Ok::<(), domain_key::KeyParseError>(())
+/// ```
+#[cfg(feature = "std")]
+#[allow(clippy::type_complexity)]
+pub fn validate_batch_counted<T: KeyDomain, I>(
+    keys: I,
+) -> (HashMap<Key<T>, usize>, Vec<(String, KeyParseError)>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut counts = HashMap::new();
+    let mut invalid = Vec::new();
+
+    for key in keys {
+        let key_str = key.as_ref();
+        match Key::<T>::new(key_str) {
+            Ok(k) => *counts.entry(k).or_insert(0) += 1,
+            Err(e) => invalid.push((key_str.to_string(), e)),
+        }
+    }
+
+    (counts, invalid)
+}
+
+/// Error code used when reading keys from a reader fails
+#[cfg(feature = "std")]
+pub const READ_ERROR_CODE: u32 = 3002;
+
+/// Validate newline-delimited keys from a reader
+///
+/// Lines are streamed from `reader`; blank lines and lines starting with `#`
+/// (after trimming) are skipped. Each remaining line yields its 1-based line
+/// number together with the result of creating a key from it, which makes
+/// file-position-aware diagnostics straightforward.
+///
+/// If reading fails, a `KeyParseError::Custom` error with
+/// [`READ_ERROR_CODE`] is yielded for the offending line and iteration stops.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use domain_key::{KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+///
+/// let input = Cursor::new("# allow-list\nuser_1\n\nbad key\n");
+/// let results: Vec<_> = validation::validate_reader::<TestDomain, _>(input).collect();
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].0, 2);
+/// assert!(results[0].1.is_ok());
+/// assert_eq!(results[1].0, 4);
+/// assert!(results[1].1.is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn validate_reader<T: KeyDomain, R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = (usize, Result<Key<T>, KeyParseError>)> {
+    reader
+        .lines()
+        .enumerate()
+        .scan(false, |failed, (index, line)| {
+            if *failed {
+                return None;
+            }
+            let line_number = index + 1;
+            match line {
+                Ok(line) => {
+                    let trimmed = line.trim();
+                    if trimmed.is_empty() || trimmed.starts_with('#') {
+                        Some(None)
+                    } else {
+                        Some(Some((line_number, Key::new(trimmed))))
+                    }
+                }
+                Err(e) => {
+                    *failed = true;
+                    let error = KeyParseError::custom_with_source(
+                        READ_ERROR_CODE,
+                        "failed to read line",
+                        &e,
+                    );
+                    Some(Some((line_number, Err(error))))
+                }
+            }
+        })
+        .flatten()
+}
+
+/// Validate newline-delimited keys from a reader without building keys
+///
+/// Lines are read lazily, so arbitrarily large inputs use constant memory.
+/// Each line yields its 1-based line number, its trimmed content and the
+/// validation result. Blank lines are skipped by default; see
+/// [`LineValidator::skip_blank`]. Unlike [`validate_reader`], `#` lines are
+/// not treated as comments.
+///
+/// If reading fails, the offending line yields empty content and a
+/// `KeyParseError::Custom` error with [`READ_ERROR_CODE`], and iteration
+/// stops.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use domain_key::{KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+///
+/// let input = Cursor::new("user_1\n\n  bad key \n");
+/// let results: Vec<_> = validation::validate_lines::<TestDomain, _>(input).collect();
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!((results[0].0, results[0].1.as_str()), (1, "user_1"));
+/// assert_eq!((results[1].0, results[1].1.as_str()), (3, "bad key"));
+/// assert!(results[1].2.is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn validate_lines<T: KeyDomain, R: BufRead>(reader: R) -> LineValidator<T, R> {
+    LineValidator {
+        lines: reader.lines(),
+        line_number: 0,
+        skip_blank: true,
+        failed: false,
+        _phantom: core::marker::PhantomData,
+    }
+}
+
+/// Streaming iterator returned by [`validate_lines`]
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct LineValidator<T: KeyDomain, R> {
+    lines: std::io::Lines<R>,
+    line_number: usize,
+    skip_blank: bool,
+    failed: bool,
+    _phantom: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, R: BufRead> LineValidator<T, R> {
+    /// Set whether blank lines are skipped (default) or reported as errors
+    #[must_use]
+    pub fn skip_blank(mut self, skip: bool) -> Self {
+        self.skip_blank = skip;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KeyDomain, R: BufRead> Iterator for LineValidator<T, R> {
+    type Item = (usize, String, Result<(), KeyParseError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            let line = self.lines.next()?;
+            self.line_number += 1;
+            match line {
+                Ok(line) => {
+                    let trimmed = line.trim();
+                    if self.skip_blank && trimmed.is_empty() {
+                        continue;
+                    }
+                    let result = validate_key::<T>(trimmed);
+                    return Some((self.line_number, trimmed.to_string(), result));
+                }
+                Err(e) => {
+                    self.failed = true;
+                    let error = KeyParseError::custom_with_source(
+                        READ_ERROR_CODE,
+                        "failed to read line",
+                        &e,
+                    );
+                    return Some((self.line_number, String::new(), Err(error)));
+                }
+            }
+        }
+    }
+}
+
 /// Filter a collection of strings to only include valid keys
 ///
 /// This function takes an iterator of strings and returns only those
@@ -587,6 +909,159 @@ impl ValidationResult {
             .filter_map(|s| Key::from_string(s).ok())
             .collect()
     }
+
+    /// Convert valid strings to keys while keeping the original errors
+    ///
+    /// Strings validated for a different domain than `T` may still fail to
+    /// convert; those are appended to the returned errors instead of being
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_key::{KeyDomain, ValidationBuilder};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    /// struct TestDomain;
+    /// impl KeyDomain for TestDomain {
+    ///     const DOMAIN_NAME: &'static str = "test";
+    /// }
+    ///
+    /// let result = ValidationBuilder::<TestDomain>::new()
+    ///     .validate(&["good_key", "bad key"]);
+    /// let (keys, errors) = result.partition::<TestDomain>();
+    ///
+    /// assert_eq!(keys.len(), 1);
+    /// assert_eq!(errors[0].0, "bad key");
+    /// ```
+    #[must_use]
+    pub fn partition<T: KeyDomain>(self) -> (Vec<Key<T>>, Vec<(String, KeyParseError)>) {
+        let mut errors = self.errors;
+        let mut keys = Vec::with_capacity(self.valid.len());
+
+        for s in self.valid {
+            match Key::new(&s) {
+                Ok(key) => keys.push(key),
+                Err(e) => errors.push((s, e)),
+            }
+        }
+
+        (keys, errors)
+    }
+}
+
+// ============================================================================
+// VALIDATION BUDGET
+// ============================================================================
+
+/// Budget that caps the total validation work spent on untrusted input
+///
+/// A budget tracks how many input bytes have been scanned and how many
+/// validation passes have run. Pass the same budget to every
+/// [`Key::new_with_budget`] call made for a request to bound the total cost,
+/// even when the domain has an expensive custom validator. Once a limit
+/// would be exceeded, creation fails with a `KeyParseError::Custom` error
+/// carrying [`ValidationBudget::EXCEEDED_CODE`].
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{Key, KeyDomain, ValidationBudget};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+/// type TestKey = Key<TestDomain>;
+///
+/// let mut budget = ValidationBudget::new(64, 2);
+/// assert!(TestKey::new_with_budget("first", &mut budget).is_ok());
+/// assert!(TestKey::new_with_budget("second", &mut budget).is_ok());
+///
+/// let err = TestKey::new_with_budget("third", &mut budget).unwrap_err();
+/// assert_eq!(err.code(), ValidationBudget::EXCEEDED_CODE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationBudget {
+    max_bytes: usize,
+    max_validations: usize,
+    bytes_used: usize,
+    validations_used: usize,
+}
+
+impl ValidationBudget {
+    /// Error code used when a validation budget is exhausted
+    pub const EXCEEDED_CODE: u32 = 3001;
+
+    /// Create a budget allowing `max_bytes` scanned input bytes and
+    /// `max_validations` validation passes
+    #[must_use]
+    pub const fn new(max_bytes: usize, max_validations: usize) -> Self {
+        Self {
+            max_bytes,
+            max_validations,
+            bytes_used: 0,
+            validations_used: 0,
+        }
+    }
+
+    /// Create a budget that never runs out
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self::new(usize::MAX, usize::MAX)
+    }
+
+    /// Number of input bytes that can still be scanned
+    #[must_use]
+    pub const fn remaining_bytes(&self) -> usize {
+        self.max_bytes - self.bytes_used
+    }
+
+    /// Number of validation passes that can still run
+    #[must_use]
+    pub const fn remaining_validations(&self) -> usize {
+        self.max_validations - self.validations_used
+    }
+
+    /// Returns true if no further validation pass fits in the budget
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.validations_used >= self.max_validations || self.bytes_used >= self.max_bytes
+    }
+
+    /// Charge one validation pass over `bytes` input bytes
+    ///
+    /// The budget is left untouched if the charge doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeyParseError::Custom` with [`Self::EXCEEDED_CODE`] if the
+    /// charge would exceed either limit
+    pub fn charge(&mut self, bytes: usize) -> Result<(), KeyParseError> {
+        if bytes > self.remaining_bytes() {
+            return Err(KeyParseError::custom(
+                Self::EXCEEDED_CODE,
+                "validation budget exceeded: too many bytes scanned",
+            ));
+        }
+        if self.remaining_validations() == 0 {
+            return Err(KeyParseError::custom(
+                Self::EXCEEDED_CODE,
+                "validation budget exceeded: too many validations",
+            ));
+        }
+
+        self.bytes_used += bytes;
+        self.validations_used += 1;
+        Ok(())
+    }
+}
+
+impl Default for ValidationBudget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
 }
 
 // ============================================================================
@@ -657,6 +1132,273 @@ where
     }
 }
 
+/// Validate a key against rules loaded at runtime
+///
+/// Applies the length limits, character set and pattern of a
+/// [`RuntimeDomain`] to `key` as given, without trimming or lowercasing.
+/// Use [`RuntimeDomain::new_key`] to normalize and create the key as well.
+///
+/// # Arguments
+///
+/// * `domain` - The runtime constraints to check against
+/// * `key` - The key string to validate
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{validation, RuntimeDomain};
+///
+/// let domain = RuntimeDomain {
+///     name: "tag".to_string(),
+///     max_length: 8,
+///     ..RuntimeDomain::default()
+/// };
+///
+/// assert!(validation::validate_runtime(&domain, "rust").is_ok());
+/// assert!(validation::validate_runtime(&domain, "much_too_long").is_err());
+/// assert!(validation::validate_runtime(&domain, "no spaces").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `KeyParseError` describing the first rule the key violates
+pub fn validate_runtime(domain: &RuntimeDomain, key: &str) -> Result<(), KeyParseError> {
+    if key.is_empty() {
+        return Err(KeyParseError::Empty);
+    }
+
+    let max_length = domain.max_length.min(DynamicDomain::MAX_LENGTH);
+    if key.len() > max_length {
+        return Err(KeyParseError::TooLong {
+            max_length,
+            actual_length: key.len(),
+        });
+    }
+
+    if key.len() < domain.min_length {
+        return Err(KeyParseError::TooShort {
+            min_length: domain.min_length,
+            actual_length: key.len(),
+        });
+    }
+
+    if let Some((position, character)) = key.char_indices().find(|&(_, c)| !domain.allows(c)) {
+        return Err(KeyParseError::InvalidCharacter {
+            character,
+            position,
+            expected: Some("allowed by runtime domain"),
+        });
+    }
+
+    if let Some(pattern) = &domain.pattern {
+        if !domain::glob_match(pattern, key) {
+            return Err(KeyParseError::domain_error(
+                DynamicDomain::DOMAIN_NAME,
+                format!(
+                    "key does not match pattern '{pattern}' of domain '{}'",
+                    domain.name
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that a domain's normalization is idempotent
+///
+/// Normalizing an already normalized key must not change it again, otherwise
+/// equal keys can end up with different representations. This helper is meant
+/// for domain authors to call from their own tests with representative inputs.
+///
+/// # Arguments
+///
+/// * `samples` - Raw inputs to normalize
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{KeyDomain, validation};
+/// use std::borrow::Cow;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+///     const HAS_CUSTOM_NORMALIZATION: bool = true;
+///
+///     fn normalize_domain(key: Cow<'_, str>) -> Cow<'_, str> {
+///         Cow::Owned(key.replace('-', "_"))
+///     }
+/// }
+///
+/// assert!(validation::assert_idempotent_normalization::<TestDomain>(&["Some-Key", "x"]).is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Returns a description of the first sample whose second normalization
+/// differs from the first
+pub fn assert_idempotent_normalization<T: KeyDomain>(samples: &[&str]) -> Result<(), String> {
+    for sample in samples {
+        let once = Key::<T>::normalize::<T>(sample);
+        let twice = Key::<T>::normalize::<T>(&once);
+        if once != twice {
+            return Err(format!(
+                "normalization of {sample:?} in domain '{}' is not idempotent: {:?} then {:?}",
+                T::DOMAIN_NAME,
+                once,
+                twice
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that re-creating each valid key from its string reproduces it
+///
+/// For every sample that is a valid key, a second key is created from the
+/// first key's `as_str()` and compared for equality and equal hashes. A
+/// mismatch means stored keys would change when loaded again. Invalid samples
+/// are skipped. Like [`assert_idempotent_normalization`], this is intended
+/// for tests of custom domains.
+///
+/// # Arguments
+///
+/// * `samples` - Raw inputs to create keys from
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_key::{KeyDomain, validation};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct TestDomain;
+/// impl KeyDomain for TestDomain {
+///     const DOMAIN_NAME: &'static str = "test";
+/// }
+///
+/// assert!(validation::assert_roundtrip::<TestDomain>(&["User_1", " spaced ", "bad key"]).is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Returns a description of the first sample whose re-created key differs
+pub fn assert_roundtrip<T: KeyDomain>(samples: &[&str]) -> Result<(), String> {
+    for sample in samples {
+        let Ok(original) = Key::<T>::new(sample) else {
+            continue;
+        };
+
+        match Key::<T>::new(original.as_str()) {
+            Ok(recreated) if recreated == original && recreated.hash() == original.hash() => {}
+            Ok(recreated) => {
+                return Err(format!(
+                    "key {sample:?} in domain '{}' does not round-trip: {:?} became {:?}",
+                    T::DOMAIN_NAME,
+                    original.as_str(),
+                    recreated.as_str()
+                ));
+            }
+            Err(e) => {
+                return Err(format!(
+                    "key {sample:?} in domain '{}' does not round-trip: {:?} is rejected: {e}",
+                    T::DOMAIN_NAME,
+                    original.as_str()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// PATTERN VALIDATION
+// ============================================================================
+
+/// Check that a key matches a regular expression
+///
+/// Intended for use in `KeyDomain::validate_domain_rules` when a domain's
+/// rule is easiest to state as a pattern. The whole key must match, so
+/// anchor the pattern with `^...$`. Errors are `DomainValidation` with the
+/// pattern in the message; the key pipeline fills in the domain name.
+///
+/// A regex is much slower than a hand-written character check and its
+/// per-key cost grows with the pattern, so prefer `allowed_characters` and
+/// friends for plain character rules and reserve this for real structure.
+/// Compile the pattern once with [`cached_regex`].
+///
+/// # Arguments
+///
+/// * `key` - The key string to check
+/// * `re` - The compiled pattern
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::OnceLock;
+/// use domain_key::{validation, Key, KeyDomain, KeyParseError};
+/// use regex::Regex;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct EmailDomain;
+/// impl KeyDomain for EmailDomain {
+///     const DOMAIN_NAME: &'static str = "email";
+///     const HAS_CUSTOM_VALIDATION: bool = true;
+///
+///     fn allowed_characters(c: char) -> bool {
+///         c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '@')
+///     }
+///
+///     fn validate_domain_rules(key: &str) -> Result<(), KeyParseError> {
+///         static EMAIL: OnceLock<Regex> = OnceLock::new();
+///         let re = validation::cached_regex(&EMAIL, r"^[a-z0-9._-]+@[a-z0-9-]+(\.[a-z0-9-]+)+$");
+///         validation::matches_pattern(key, re)
+///     }
+/// }
+///
+/// assert!(Key::<EmailDomain>::new("alice@example.com").is_ok());
+/// let err = Key::<EmailDomain>::new("alice.example.com").unwrap_err();
+/// assert!(matches!(err, KeyParseError::DomainValidation { domain: "email", .. }));
+/// assert!(err.to_string().contains("[a-z0-9._-]+@"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `KeyParseError::DomainValidation` if `key` does not match `re`
+#[cfg(feature = "regex")]
+pub fn matches_pattern(key: &str, re: &regex::Regex) -> Result<(), KeyParseError> {
+    if re.is_match(key) {
+        Ok(())
+    } else {
+        Err(KeyParseError::domain_error(
+            "pattern",
+            format!("Key does not match pattern `{}`", re.as_str()),
+        ))
+    }
+}
+
+/// Compile a pattern once and cache it in a `OnceLock`
+///
+/// Returns the cached regex, compiling `pattern` on first use. Declare the
+/// cell as a `static` next to the domain so every validation reuses it.
+///
+/// # Panics
+///
+/// Panics if `pattern` is not a valid regular expression. Patterns are
+/// expected to be literals, so this is a programming error.
+#[cfg(feature = "regex")]
+#[must_use]
+pub fn cached_regex<'a>(
+    cell: &'a std::sync::OnceLock<regex::Regex>,
+    pattern: &str,
+) -> &'a regex::Regex {
+    cell.get_or_init(|| {
+        regex::Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("invalid key pattern {pattern:?}: {e}"))
+    })
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -664,6 +1406,12 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::borrow::Cow;
+    #[cfg(feature = "std")]
+    use std::borrow::Cow;
+    #[cfg(feature = "regex")]
+    use std::sync::OnceLock;
 
     // Test domain
     #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -704,6 +1452,59 @@ mod tests {
         assert!(info.contains("Examples: example1, example2"));
     }
 
+    #[test]
+    fn test_validation_info_fmt() {
+        struct Sink<'a>(&'a mut [u8], usize);
+        impl Write for Sink<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.1 + s.len();
+                self.0
+                    .get_mut(self.1..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.1 = end;
+                Ok(())
+            }
+        }
+
+        let mut buf = [0u8; 512];
+        let mut sink = Sink(&mut buf, 0);
+        validation_info_fmt::<TestDomain, _>(&mut sink).unwrap();
+        let len = sink.1;
+
+        let written = core::str::from_utf8(&buf[..len]).unwrap();
+        assert_eq!(written, validation_info::<TestDomain>());
+
+        // A sink that runs out of space surfaces the error
+        let mut small = [0u8; 8];
+        assert!(validation_info_fmt::<TestDomain, _>(&mut Sink(&mut small, 0)).is_err());
+    }
+
+    #[test]
+    fn test_validation_budget() {
+        let mut budget = ValidationBudget::new(10, 5);
+
+        assert!(Key::<TestDomain>::new_with_budget("abcdef", &mut budget).is_ok());
+        assert_eq!(budget.remaining_bytes(), 4);
+        assert_eq!(budget.remaining_validations(), 4);
+
+        // Too many bytes: rejected without consuming the budget
+        let err = Key::<TestDomain>::new_with_budget("abcdef", &mut budget).unwrap_err();
+        assert_eq!(err.code(), ValidationBudget::EXCEEDED_CODE);
+        assert_eq!(budget.remaining_bytes(), 4);
+
+        // Failed validations still consume budget
+        assert!(Key::<TestDomain>::new_with_budget("b d", &mut budget).is_err());
+        assert_eq!(budget.remaining_bytes(), 1);
+
+        let mut budget = ValidationBudget::new(100, 1);
+        assert!(Key::<TestDomain>::new_with_budget("a", &mut budget).is_ok());
+        assert!(budget.is_exhausted());
+        assert!(Key::<TestDomain>::new_with_budget("b", &mut budget).is_err());
+
+        assert!(!ValidationBudget::default().is_exhausted());
+    }
+
     #[test]
     fn test_validate_batch() {
         let keys = vec!["valid1", "", "valid2", "bad key"];
@@ -715,6 +1516,86 @@ mod tests {
         assert!(valid.contains(&"valid2".to_string()));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_batch_counted() {
+        let keys = vec!["valid1", "", "valid2", "VALID1", "bad key", "valid1"];
+        let (counts, invalid) = validate_batch_counted::<TestDomain, _>(&keys);
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&Key::<TestDomain>::new("valid1").unwrap()], 3);
+        assert_eq!(counts[&Key::<TestDomain>::new("valid2").unwrap()], 1);
+        assert_eq!(invalid.len(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_reader() {
+        use std::io::{self, BufReader, Cursor, Read};
+
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let input = Cursor::new("valid1\r\n  # comment\n\n  valid2  \nbad key\n");
+        let results: Vec<_> = validate_reader::<TestDomain, _>(input).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, 1);
+        assert_eq!(results[0].1.as_ref().unwrap().as_str(), "valid1");
+        assert_eq!(results[1].0, 4);
+        assert_eq!(results[1].1.as_ref().unwrap().as_str(), "valid2");
+        assert_eq!(results[2].0, 5);
+        assert!(results[2].1.is_err());
+
+        let results: Vec<_> =
+            validate_reader::<TestDomain, _>(BufReader::new(FailingReader)).collect();
+        assert_eq!(results.len(), 1);
+        let err = results[0].1.as_ref().unwrap_err();
+        assert_eq!(err.code(), READ_ERROR_CODE);
+        assert!(err.to_string().contains("disk on fire"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_validate_lines() {
+        use std::io::{self, BufReader, Cursor, Read};
+
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let input = "valid1\r\n\n  valid2  \nbad key\n   \nlast";
+        let results: Vec<_> = validate_lines::<TestDomain, _>(Cursor::new(input)).collect();
+        let lines: Vec<_> = results.iter().map(|(n, s, _)| (*n, s.as_str())).collect();
+        assert_eq!(
+            lines,
+            [(1, "valid1"), (3, "valid2"), (4, "bad key"), (6, "last")]
+        );
+        assert!(results[0].2.is_ok());
+        assert!(results[1].2.is_ok());
+        assert!(results[2].2.is_err());
+        assert!(results[3].2.is_ok());
+
+        let all: Vec<_> = validate_lines::<TestDomain, _>(Cursor::new(input))
+            .skip_blank(false)
+            .collect();
+        assert_eq!(all.len(), 6);
+        assert_eq!(all[1].0, 2);
+        assert_eq!(all[1].2, Err(KeyParseError::Empty));
+
+        let results: Vec<_> =
+            validate_lines::<TestDomain, _>(BufReader::new(FailingReader)).collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].2.as_ref().unwrap_err().code(), READ_ERROR_CODE);
+    }
+
     #[test]
     fn test_filter_valid() {
         let keys = vec!["valid1", "", "valid2", "bad key"];
@@ -850,6 +1731,28 @@ mod tests {
         assert_eq!(keys.len(), 2);
     }
 
+    #[test]
+    fn test_validation_result_partition() {
+        let result =
+            ValidationBuilder::<TestDomain>::new().validate(["good_key", "bad key", "other"]);
+        let (keys, errors) = result.partition::<TestDomain>();
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].as_str(), "good_key");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "bad key");
+
+        // Strings valid for another domain are reported, not dropped
+        let result = ValidationResult {
+            total_processed: 1,
+            valid: vec!["a".repeat(40)],
+            errors: Vec::new(),
+        };
+        let (keys, errors) = result.partition::<TestDomain>();
+        assert!(keys.is_empty());
+        assert!(matches!(errors[0].1, KeyParseError::TooLong { .. }));
+    }
+
     #[test]
     fn test_strict_validator() {
         let validator = strict_validator::<TestDomain>();
@@ -874,6 +1777,121 @@ mod tests {
         assert_eq!(result.error_count(), 1);
     }
 
+    #[test]
+    fn test_canonicalize() {
+        for input in ["Hello_World", "  padded  ", "already_canonical"] {
+            let key = Key::<TestDomain>::new(input).unwrap();
+            assert_eq!(canonicalize::<TestDomain>(input).unwrap(), key.as_str());
+        }
+        assert!(canonicalize::<TestDomain>("").is_err());
+        assert!(canonicalize::<TestDomain>("bad key").is_err());
+    }
+
+    #[test]
+    fn test_length_ok() {
+        assert!(length_ok::<TestDomain>("a"));
+        assert!(length_ok::<TestDomain>(&"a".repeat(32)));
+        assert!(!length_ok::<TestDomain>(&"a".repeat(33)));
+        assert!(!length_ok::<TestDomain>(""));
+
+        // Characters are not inspected
+        assert!(length_ok::<TestDomain>("not a key!"));
+    }
+
+    #[test]
+    fn test_validate_runtime() {
+        let domain = RuntimeDomain {
+            name: "order".to_string(),
+            min_length: 3,
+            max_length: 12,
+            allowed_characters: "-".to_string(),
+            case_insensitive: true,
+            pattern: Some("ord-*".to_string()),
+        };
+
+        assert!(validate_runtime(&domain, "ord-42").is_ok());
+        assert!(matches!(
+            validate_runtime(&domain, ""),
+            Err(KeyParseError::Empty)
+        ));
+        assert!(matches!(
+            validate_runtime(&domain, "ord-1234567890"),
+            Err(KeyParseError::TooLong { max_length: 12, .. })
+        ));
+        assert!(matches!(
+            validate_runtime(&domain, "ord_1"),
+            Err(KeyParseError::InvalidCharacter {
+                character: '_',
+                position: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            validate_runtime(&domain, "inv-42"),
+            Err(KeyParseError::DomainValidation { .. })
+        ));
+
+        let key = domain.new_key("  ORD-7 ").unwrap();
+        assert_eq!(key.as_str(), "ord-7");
+        assert_eq!(key.domain(), "dynamic");
+
+        let sensitive = RuntimeDomain {
+            case_insensitive: false,
+            pattern: None,
+            ..domain
+        };
+        assert_eq!(sensitive.new_key("Ord-7").unwrap().as_str(), "Ord-7");
+    }
+
+    #[test]
+    fn test_assert_roundtrip() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct SuffixDomain;
+
+        impl KeyDomain for SuffixDomain {
+            const DOMAIN_NAME: &'static str = "suffix";
+            const HAS_CUSTOM_NORMALIZATION: bool = true;
+
+            fn normalize_domain(key: Cow<'_, str>) -> Cow<'_, str> {
+                // Broken on purpose: appends on every normalization
+                Cow::Owned(format!("{key}_x"))
+            }
+        }
+
+        assert!(assert_roundtrip::<TestDomain>(&["Key", "bad key", "valid_one"]).is_ok());
+        assert!(assert_roundtrip::<SuffixDomain>(&["bad key"]).is_ok());
+
+        let err = assert_roundtrip::<SuffixDomain>(&["abc"]).unwrap_err();
+        assert!(err.contains("\"abc_x\" became \"abc_x_x\""));
+    }
+
+    #[test]
+    fn test_assert_idempotent_normalization() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct AppendingDomain;
+
+        impl KeyDomain for AppendingDomain {
+            const DOMAIN_NAME: &'static str = "appending";
+            const HAS_CUSTOM_NORMALIZATION: bool = true;
+
+            fn normalize_domain(key: Cow<'_, str>) -> Cow<'_, str> {
+                // Broken on purpose: doubles every 'x'
+                if key.contains('x') {
+                    Cow::Owned(key.replace('x', "xx"))
+                } else {
+                    key
+                }
+            }
+        }
+
+        assert!(assert_idempotent_normalization::<TestDomain>(&["Key", " spaced "]).is_ok());
+        assert!(assert_idempotent_normalization::<AppendingDomain>(&["abc"]).is_ok());
+
+        let err = assert_idempotent_normalization::<AppendingDomain>(&["abc", "box"]).unwrap_err();
+        assert!(err.contains("\"box\""));
+        assert!(err.contains("appending"));
+    }
+
     #[test]
     fn test_quick_convert() {
         let strings = vec!["key1", "key2", "key3"];
@@ -903,4 +1921,33 @@ mod tests {
         assert_eq!(result.valid_count(), 1);
         assert_eq!(result.error_count(), 1);
     }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_matches_pattern() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct VersionDomain;
+        impl KeyDomain for VersionDomain {
+            const DOMAIN_NAME: &'static str = "version";
+            const HAS_CUSTOM_VALIDATION: bool = true;
+            fn validate_domain_rules(key: &str) -> Result<(), KeyParseError> {
+                static VERSION: OnceLock<regex::Regex> = OnceLock::new();
+                matches_pattern(key, cached_regex(&VERSION, r"^v[0-9]+(_[0-9]+)*$"))
+            }
+        }
+
+        let re = regex::Regex::new("^[a-z]+$").unwrap();
+        assert!(matches_pattern("abc", &re).is_ok());
+        let err = matches_pattern("abc1", &re).unwrap_err();
+        assert!(err.to_string().contains("`^[a-z]+$`"));
+
+        assert!(Key::<VersionDomain>::new("v1_2_3").is_ok());
+        match Key::<VersionDomain>::new("v1_x") {
+            Err(KeyParseError::DomainValidation { domain, message }) => {
+                assert_eq!(domain, "version");
+                assert!(message.contains("^v[0-9]+"));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
 }